@@ -0,0 +1,143 @@
+use aoc_utils::grid::{ByteGrid, Direction, Grid};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+const SIZE: usize = 140;
+
+/// Puzzle sized grid of `@` rolls and `.` floor, the same every run
+fn input() -> String {
+    let mut seed: u32 = 12345;
+    let mut text = String::with_capacity(SIZE * (SIZE + 1));
+    for _ in 0..SIZE {
+        for _ in 0..SIZE {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            text.push(if (seed >> 16).is_multiple_of(3) {
+                '.'
+            } else {
+                '@'
+            });
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Removes rolls with fewer than 4 neighbouring rolls until none can be removed
+fn remove_rolls_vec(grid: &mut [Vec<char>]) -> usize {
+    let mut removed = 0;
+    loop {
+        let mut picked = Vec::new();
+        for r in 0..grid.len() {
+            for c in 0..grid[r].len() {
+                if grid[r][c] != '@' {
+                    continue;
+                }
+                let rolls = Direction::all()
+                    .into_iter()
+                    .filter_map(|d| d.checked_move_point((r, c)))
+                    .filter(|&(nr, nc)| grid.get(nr).and_then(|row| row.get(nc)) == Some(&'@'))
+                    .count();
+                if rolls < 4 {
+                    picked.push((r, c));
+                }
+            }
+        }
+        if picked.is_empty() {
+            return removed;
+        }
+        removed += picked.len();
+        for (r, c) in picked {
+            grid[r][c] = '.';
+        }
+    }
+}
+
+fn remove_rolls_grid(grid: &mut Grid<char>) -> usize {
+    let mut removed = 0;
+    loop {
+        let picked: Vec<_> = grid
+            .iter()
+            .filter(|&(p, &c)| c == '@' && grid.neighbors8(p).filter(|n| *n.2 == '@').count() < 4)
+            .map(|(p, _)| p)
+            .collect();
+        if picked.is_empty() {
+            return removed;
+        }
+        removed += picked.len();
+        for p in picked {
+            grid[p] = '.';
+        }
+    }
+}
+
+fn remove_rolls_bytes(grid: &mut ByteGrid) -> usize {
+    let mut removed = 0;
+    loop {
+        let picked: Vec<_> = grid
+            .iter()
+            .filter(|&(p, b)| b == b'@' && grid.neighbors8(p).filter(|n| n.2 == b'@').count() < 4)
+            .map(|(p, _)| p)
+            .collect();
+        if picked.is_empty() {
+            return removed;
+        }
+        removed += picked.len();
+        for p in picked {
+            grid[p] = b'.';
+        }
+    }
+}
+
+fn parse(c: &mut Criterion) {
+    let text = input();
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("vec_of_chars", |b| {
+        b.iter(|| {
+            black_box(&text)
+                .lines()
+                .map(|line| line.chars().collect())
+                .collect::<Vec<Vec<char>>>()
+        })
+    });
+    group.bench_function("grid_char", |b| {
+        b.iter(|| black_box(&text).parse::<Grid<char>>().unwrap())
+    });
+    // the bytes are cloned outside the measurement, reading the file gives an owned Vec anyway
+    group.bench_function("byte_grid", |b| {
+        b.iter_batched(
+            || text.clone().into_bytes(),
+            |bytes| ByteGrid::from_bytes(bytes).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn simulate(c: &mut Criterion) {
+    let text = input();
+    let vec: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+    let grid: Grid<char> = text.parse().unwrap();
+    let bytes = ByteGrid::from_bytes(text.clone().into_bytes()).unwrap();
+    assert_eq!(
+        remove_rolls_vec(&mut vec.clone()),
+        remove_rolls_bytes(&mut bytes.clone())
+    );
+
+    let mut group = c.benchmark_group("remove_rolls");
+    group.bench_function("vec_of_chars", |b| {
+        b.iter_batched_ref(
+            || vec.clone(),
+            |g| remove_rolls_vec(g),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("grid_char", |b| {
+        b.iter_batched_ref(|| grid.clone(), remove_rolls_grid, BatchSize::SmallInput)
+    });
+    group.bench_function("byte_grid", |b| {
+        b.iter_batched_ref(|| bytes.clone(), remove_rolls_bytes, BatchSize::SmallInput)
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse, simulate);
+criterion_main!(benches);
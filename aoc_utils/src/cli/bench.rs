@@ -0,0 +1,261 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::{crate_dir, print_table, Input, USAGE};
+use crate::solution::{is_unsolved, Day, Part};
+
+/// Options of `aoc bench`, by default every day on `prod`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchArgs {
+    pub day: Option<u8>,
+    pub input: Input,
+    /// Runs thrown away before measuring, so caches and the allocator are warm
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl BenchArgs {
+    pub fn parse(args: &[&str]) -> Result<Self> {
+        let mut bench = BenchArgs {
+            day: None,
+            input: Input::Named("prod".to_string()),
+            warmup: 3,
+            runs: 20,
+        };
+        let mut args = args.iter();
+        while let Some(&arg) = args.next() {
+            match arg {
+                "--input" => bench.input = Input::parse(args.next().context(USAGE)?),
+                "--warmup" => bench.warmup = args.next().context(USAGE)?.parse().context(USAGE)?,
+                "--runs" => bench.runs = args.next().context(USAGE)?.parse().context(USAGE)?,
+                day if bench.day.is_none() => bench.day = Some(day.parse().context(USAGE)?),
+                _ => bail!(USAGE),
+            }
+        }
+        if bench.runs == 0 {
+            bail!("--runs needs at least one run");
+        }
+        Ok(bench)
+    }
+}
+
+/// Summary of the samples of one stage, in microseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+}
+
+impl Stats {
+    /// `samples` can't be empty. The p95 is the nearest rank, so below 20 samples it's the max.
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut micros: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64 / 1e3).collect();
+        micros.sort_by(f64::total_cmp);
+        let n = micros.len();
+        let median = if n % 2 == 1 {
+            micros[n / 2]
+        } else {
+            (micros[n / 2 - 1] + micros[n / 2]) / 2.0
+        };
+        let p95 = micros[(n as f64 * 0.95).ceil() as usize - 1];
+        Stats {
+            min: micros[0],
+            median,
+            mean: micros.iter().sum::<f64>() / n as f64,
+            p95,
+        }
+    }
+}
+
+/// Timings of one part of a day, parse and solve apart
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bench {
+    pub day: u8,
+    pub part: u8,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Bench {
+    pub fn median(&self) -> f64 {
+        self.parse.median + self.solve.median
+    }
+}
+
+/// The last results of every input, kept in `bench.json` of the year crate. Timings are
+/// only comparable on one machine, so the file isn't committed.
+pub type Results = BTreeMap<String, Vec<Bench>>;
+
+pub fn load(path: &Path) -> Result<Results> {
+    match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text).with_context(|| format!("in {}", path.display())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Results::new()),
+        Err(e) => Err(e).context(format!("couldn't read {}", path.display())),
+    }
+}
+
+/// Warms up and then runs `part` `args.runs` times, `None` when it isn't solved
+fn bench_part(day: &Day, input: &str, part: Part, args: &BenchArgs) -> Result<Option<Bench>> {
+    let (mut parse, mut solve) = (Vec::new(), Vec::new());
+    for i in 0..args.warmup + args.runs {
+        let run = match day.run(input, part) {
+            Ok(run) => run,
+            Err(e) if is_unsolved(&e) => return Ok(None),
+            Err(e) => return Err(e).context(format!("day {} {}", day.day, part)),
+        };
+        if i >= args.warmup {
+            parse.push(run.parse);
+            solve.push(run.solve);
+        }
+    }
+    Ok(Some(Bench {
+        day: day.day,
+        part: part.number(),
+        parse: Stats::new(&parse),
+        solve: Stats::new(&solve),
+    }))
+}
+
+/// Change of the median of parse and solve together, in percent
+fn change(bench: &Bench, previous: &[Bench]) -> Option<f64> {
+    let before = previous
+        .iter()
+        .find(|b| (b.day, b.part) == (bench.day, bench.part))?;
+    Some((bench.median() / before.median() - 1.0) * 100.0)
+}
+
+/// `aoc bench`, prints the timings next to the change since the last run and saves them
+pub fn bench(year: u16, days: &[&Day], args: &BenchArgs) -> Result<()> {
+    let path = crate_dir(year).join("bench.json");
+    let mut results = load(&path)?;
+    let key = match &args.input {
+        Input::Named(name) => name.clone(),
+        Input::Path(path) => path.display().to_string(),
+    };
+    let previous = results.remove(&key).unwrap_or_default();
+    let mut benches = Vec::new();
+    for day in days {
+        let path = args.input.path(year, day.day);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) if args.day.is_none() => {
+                println!("day {}: skipped, {}: {}", day.day, path.display(), e);
+                continue;
+            }
+            Err(e) => return Err(e).context(format!("couldn't read {}", path.display())),
+        };
+        for part in Part::BOTH {
+            benches.extend(bench_part(day, &input, part, args)?);
+        }
+    }
+
+    let micros = |us: f64| format!("{:.1}", us);
+    let rows = benches
+        .iter()
+        .map(|b| {
+            let mut row = vec![b.day.to_string(), b.part.to_string()];
+            for stats in [&b.parse, &b.solve] {
+                row.extend([stats.min, stats.median, stats.mean, stats.p95].map(micros));
+            }
+            row.push(change(b, &previous).map_or(String::new(), |c| format!("{:+.1}%", c)));
+            row
+        })
+        .collect();
+    println!(
+        "{} runs after {} warmup runs, times in µs",
+        args.runs, args.warmup
+    );
+    print_table(
+        &[
+            "day",
+            "part",
+            "parse min",
+            "median",
+            "mean",
+            "p95",
+            "solve min",
+            "median",
+            "mean",
+            "p95",
+            "change",
+        ],
+        rows,
+    );
+
+    // days that weren't benched this time keep their last results
+    let mut saved = previous;
+    saved.retain(|p| !benches.iter().any(|b| (b.day, b.part) == (p.day, p.part)));
+    saved.extend(benches);
+    saved.sort_by_key(|b| (b.day, b.part));
+    results.insert(key, saved);
+    fs::write(&path, serde_json::to_string_pretty(&results)?)
+        .with_context(|| format!("couldn't write {}", path.display()))?;
+    println!("Saved to {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bench_arguments() {
+        let args = BenchArgs::parse(&["7", "--runs", "100", "--input", "test"]).unwrap();
+        assert_eq!(
+            args,
+            BenchArgs {
+                day: Some(7),
+                input: Input::Named("test".to_string()),
+                warmup: 3,
+                runs: 100,
+            }
+        );
+        assert_eq!(BenchArgs::parse(&[]).unwrap().day, None);
+        assert!(BenchArgs::parse(&["--runs", "0"]).is_err());
+        assert!(BenchArgs::parse(&["--warmup"]).is_err());
+    }
+
+    #[test]
+    fn statistics() {
+        let samples: Vec<Duration> = [5, 1, 4, 2, 3, 100].map(Duration::from_micros).to_vec();
+        let stats = Stats::new(&samples);
+        assert_eq!(
+            stats,
+            Stats {
+                min: 1.0,
+                median: 3.5,
+                mean: 115.0 / 6.0,
+                p95: 100.0,
+            }
+        );
+        let one = Stats::new(&[Duration::from_nanos(1500)]);
+        assert_eq!((one.min, one.median, one.p95), (1.5, 1.5, 1.5));
+    }
+
+    #[test]
+    fn change_since_last_run() {
+        let stats = |median| Stats {
+            min: median,
+            median,
+            mean: median,
+            p95: median,
+        };
+        let bench = |day, parse, solve| Bench {
+            day,
+            part: 1,
+            parse: stats(parse),
+            solve: stats(solve),
+        };
+        let previous = [bench(1, 10.0, 90.0)];
+        assert_eq!(change(&bench(1, 10.0, 40.0), &previous), Some(-50.0));
+        assert_eq!(change(&bench(2, 10.0, 40.0), &previous), None);
+    }
+}
@@ -0,0 +1,356 @@
+//! The `aoc` command every year crate builds, the crate only passes in its year and days.
+//!
+//! ```text
+//! usage: aoc <year> [<day>] [--part <1|2>] [--input <prod|test|path>]
+//!        aoc fetch <year> <day>
+//!        aoc extract <year> <day> <page.html> [--example <n>]... [--patch]
+//!        aoc verify <year> [<day>]
+//!        aoc bench <year> [<day>] [--input <prod|test|path>] [--warmup <n>] [--runs <n>]
+//!        aoc report <year> [--output <file.md>] [--html]
+//! ```
+mod bench;
+mod report;
+mod verify;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use crate::puzzle::{patch_expected, PuzzlePage};
+use crate::solution::{is_unsolved, Day, Manifest, Part};
+
+pub use bench::{Bench, Stats};
+pub use report::DayReport;
+pub use verify::{answer_cell, check, Check, Status};
+
+const USAGE: &str = "usage: aoc <year> [<day>] [--part <1|2>] [--input <prod|test|path>]
+       aoc fetch <year> <day>
+       aoc extract <year> <day> <page.html> [--example <n>]... [--patch]
+       aoc verify <year> [<day>]
+       aoc bench <year> [<day>] [--input <prod|test|path>] [--warmup <n>] [--runs <n>]
+       aoc report <year> [--output <file.md>] [--html]";
+
+/// Runs the command line of the `aoc` binary of `year`
+///
+/// Example usage:
+/// ```ignore
+/// fn main() -> anyhow::Result<()> {
+///     aoc_utils::cli::main(2024, rust_2024::SOLUTIONS)
+/// }
+/// ```
+pub fn main(year: u16, solutions: &[Day]) -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["fetch", year, day] => {
+            let (year, day) = puzzle(year, day)?;
+            fetch(year, day)
+        }
+        ["extract", year, day, page, options @ ..] => {
+            let (year, day) = puzzle(year, day)?;
+            extract(year, day, page, options)
+        }
+        ["verify", arg_year, rest @ ..] => {
+            check_year(year, arg_year)?;
+            let day = match rest {
+                [] => None,
+                [day] => Some(day.parse().context(USAGE)?),
+                _ => bail!(USAGE),
+            };
+            let manifest = Manifest::load(manifest_path())?;
+            verify::verify(year, &registered(year, solutions, day)?, &manifest)
+        }
+        ["bench", arg_year, rest @ ..] => {
+            check_year(year, arg_year)?;
+            let args = bench::BenchArgs::parse(rest)?;
+            bench::bench(year, &registered(year, solutions, args.day)?, &args)
+        }
+        ["report", arg_year, rest @ ..] => {
+            check_year(year, arg_year)?;
+            let args = report::ReportArgs::parse(rest)?;
+            let manifest = Manifest::load(manifest_path())?;
+            let days: Vec<&Day> = solutions.iter().collect();
+            report::report(year, &days, &manifest, &args)
+        }
+        [arg_year, rest @ ..] => {
+            check_year(year, arg_year)?;
+            let args = RunArgs::parse(rest)?;
+            run(year, solutions, &args)
+        }
+        _ => bail!(USAGE),
+    }
+}
+
+fn check_year(year: u16, arg_year: &str) -> Result<()> {
+    if arg_year.parse::<u16>().context(USAGE)? != year {
+        bail!(
+            "this is the runner of {}, use the rust_{} crate",
+            year,
+            arg_year
+        );
+    }
+    Ok(())
+}
+
+/// The registered days, or only `day` when there is one
+fn registered(year: u16, solutions: &[Day], day: Option<u8>) -> Result<Vec<&Day>> {
+    let days: Vec<&Day> = solutions
+        .iter()
+        .filter(|d| day.is_none_or(|day| d.day == day))
+        .collect();
+    if let (Some(day), true) = (day, days.is_empty()) {
+        bail!("{} day {} isn't registered", year, day);
+    }
+    Ok(days)
+}
+
+/// Every year has its own crate next to `aoc_utils`, rust_<year>
+pub fn crate_dir(year: u16) -> PathBuf {
+    let crates = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    crates.join(format!("rust_{}", year))
+}
+
+/// The answers of all years are kept in one `answers.toml` next to the year crates
+pub fn manifest_path() -> PathBuf {
+    let crates = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    crates.join("answers.toml")
+}
+
+/// Prints `rows` under `header` with every column as wide as its widest cell
+fn print_table(header: &[&str], rows: Vec<Vec<String>>) {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let line = |cells: Vec<&str>| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    };
+    line(header.to_vec());
+    for row in &rows {
+        line(row.iter().map(String::as_str).collect());
+    }
+}
+
+/// Where `aoc <year> <day>` reads its input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// `inputs/dayN.<name>` of the year crate, e.g. `prod`, `test` or `test2`
+    Named(String),
+    Path(PathBuf),
+}
+
+impl Input {
+    /// `prod` and `test` followed by anything without a `/` are named inputs,
+    /// the rest is a path
+    pub fn parse(arg: &str) -> Self {
+        let named = (arg.starts_with("prod") || arg.starts_with("test")) && !arg.contains('/');
+        if named {
+            Input::Named(arg.to_string())
+        } else {
+            Input::Path(PathBuf::from(arg))
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        match self {
+            Input::Named(name) => crate_dir(year)
+                .join("inputs")
+                .join(format!("day{}.{}", day, name)),
+            Input::Path(path) => path.clone(),
+        }
+    }
+}
+
+/// Options of a run, missing ones mean every day, both parts and the `prod` input
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RunArgs {
+    day: Option<u8>,
+    part: Option<Part>,
+    input: Input,
+}
+
+impl RunArgs {
+    fn parse(args: &[&str]) -> Result<Self> {
+        let mut run = RunArgs {
+            day: None,
+            part: None,
+            input: Input::Named("prod".to_string()),
+        };
+        let mut args = args.iter();
+        while let Some(&arg) = args.next() {
+            match arg {
+                "--part" => {
+                    run.part = match *args.next().context(USAGE)? {
+                        "1" => Some(Part::One),
+                        "2" => Some(Part::Two),
+                        _ => bail!(USAGE),
+                    }
+                }
+                "--input" => run.input = Input::parse(args.next().context(USAGE)?),
+                day if run.day.is_none() => run.day = Some(day.parse().context(USAGE)?),
+                _ => bail!(USAGE),
+            }
+        }
+        Ok(run)
+    }
+}
+
+fn run(year: u16, solutions: &[Day], args: &RunArgs) -> Result<()> {
+    let days = registered(year, solutions, args.day)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+    for day in days {
+        let path = args.input.path(year, day.day);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            // running every day shouldn't stop at the first missing input
+            Err(e) if args.day.is_none() => {
+                println!("day {}: skipped, {}: {}", day.day, path.display(), e);
+                continue;
+            }
+            Err(e) => return Err(e).context(format!("couldn't read {}", path.display())),
+        };
+        for &part in &parts {
+            match day.run(&input, part) {
+                Ok(run) if run.answer.is_art() => println!(
+                    "day {} {} (parse {:?}, solve {:?}):\n{}",
+                    day.day, part, run.parse, run.solve, run.answer
+                ),
+                Ok(run) => println!(
+                    "day {} {}: {} (parse {:?}, solve {:?})",
+                    day.day, part, run.answer, run.parse, run.solve
+                ),
+                Err(e) if is_unsolved(&e) => println!("day {} {}: {}", day.day, part, e),
+                Err(e) => return Err(e).context(format!("day {} {}", day.day, part)),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn puzzle(year: &str, day: &str) -> Result<(u16, u8)> {
+    Ok((year.parse().context(USAGE)?, day.parse().context(USAGE)?))
+}
+
+#[cfg(feature = "fetch")]
+fn fetch(year: u16, day: u8) -> Result<()> {
+    let fetched =
+        crate::fetch::Fetcher::from_env()?.input(year, day, crate_dir(year).join("inputs"))?;
+    if fetched.cached {
+        println!("{} already downloaded", fetched.path.display());
+    } else {
+        println!("Downloaded {}", fetched.path.display());
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "fetch"))]
+fn fetch(_year: u16, _day: u8) -> Result<()> {
+    bail!("aoc_utils was built without the fetch feature")
+}
+
+/// Writes the picked examples (the first one by default) as test inputs and prints or
+/// patches the expected answers of the tests
+fn extract(year: u16, day: u8, page: &str, options: &[&str]) -> Result<()> {
+    let page = PuzzlePage::parse(&fs::read_to_string(page)?);
+    let mut picked = Vec::new();
+    let mut patch = false;
+    let mut options = options.iter();
+    while let Some(&option) = options.next() {
+        match option {
+            "--patch" => patch = true,
+            "--example" => {
+                let n: usize = options.next().context(USAGE)?.parse().context(USAGE)?;
+                if n == 0 {
+                    bail!("examples are numbered from 1");
+                }
+                picked.push(n - 1);
+            }
+            _ => bail!(USAGE),
+        }
+    }
+    if picked.is_empty() {
+        picked.push(0);
+    }
+    println!("Found {} examples", page.examples.len());
+    for path in page.write_examples(crate_dir(year).join("inputs"), day, &picked)? {
+        println!("Wrote {}", path.display());
+    }
+    for (part, answer) in page.answers.iter().enumerate() {
+        println!("Part {}: expected {}", part + 1, answer);
+    }
+    if patch {
+        let file = crate_dir(year).join(format!("src/day{}.rs", day));
+        let patched = patch_expected(&fs::read_to_string(&file)?, &page.answers);
+        if patched.replaced == 0 {
+            bail!(
+                "{} has no `let expected = ..;` line to patch",
+                file.display()
+            );
+        }
+        fs::write(&file, &patched.source)?;
+        println!(
+            "Patched {} of {} expected values in {}",
+            patched.replaced,
+            patched.slots,
+            file.display()
+        );
+        if patched.replaced < page.answers.len() {
+            println!(
+                "warning: the page has {} answers, only {} fit",
+                page.answers.len(),
+                patched.replaced
+            );
+        } else if patched.slots > patched.replaced {
+            println!(
+                "warning: {} expected values are left as they were",
+                patched.slots - patched.replaced
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_arguments() {
+        assert_eq!(
+            RunArgs::parse(&["16", "--part", "2", "--input", "test2"]).unwrap(),
+            RunArgs {
+                day: Some(16),
+                part: Some(Part::Two),
+                input: Input::Named("test2".to_string()),
+            }
+        );
+        let all = RunArgs::parse(&[]).unwrap();
+        assert_eq!((all.day, all.part), (None, None));
+        assert_eq!(all.input, Input::Named("prod".to_string()));
+        assert!(RunArgs::parse(&["16", "--part", "3"]).is_err());
+        assert!(RunArgs::parse(&["16", "17"]).is_err());
+    }
+
+    #[test]
+    fn input_paths() {
+        let prod = Input::parse("prod").path(2024, 16);
+        assert!(prod.ends_with("rust_2024/inputs/day16.prod"));
+        assert_eq!(
+            Input::parse("/tmp/test.txt"),
+            Input::Path(PathBuf::from("/tmp/test.txt"))
+        );
+        assert_eq!(Input::parse("big.txt").path(2024, 1), Path::new("big.txt"));
+    }
+}
@@ -0,0 +1,287 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+
+use super::verify::{check, Check, Status};
+use super::{crate_dir, USAGE};
+use crate::solution::{Day, Manifest, Part};
+
+/// Options of `aoc report`, the Markdown goes to `REPORT.md` of the year crate by default
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportArgs {
+    pub output: Option<PathBuf>,
+    /// Also writes the report as HTML, next to the Markdown
+    pub html: bool,
+}
+
+impl ReportArgs {
+    pub fn parse(args: &[&str]) -> Result<Self> {
+        let mut report = ReportArgs {
+            output: None,
+            html: false,
+        };
+        let mut args = args.iter();
+        while let Some(&arg) = args.next() {
+            match arg {
+                "--html" => report.html = true,
+                "--output" => report.output = Some(PathBuf::from(args.next().context(USAGE)?)),
+                _ => bail!(USAGE),
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// One row of the report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u8,
+    /// Checked against the answers of every input, `NoInput` when nothing could run
+    pub status: [Status; 2],
+    /// Of the `prod` input
+    pub parse: Option<Duration>,
+    pub solve: [Option<Duration>; 2],
+}
+
+impl DayReport {
+    fn new(day: u8, checks: &[Check]) -> Self {
+        let checks: Vec<&Check> = checks.iter().filter(|c| c.day == day).collect();
+        let prod = |part| {
+            checks
+                .iter()
+                .find(|c| c.input == "prod" && c.part == part)
+                .and_then(|c| c.run.as_ref())
+        };
+        DayReport {
+            day,
+            status: Part::BOTH.map(|part| {
+                part_status(checks.iter().filter(|c| c.part == part).map(|c| c.status))
+            }),
+            parse: prod(Part::One).or(prod(Part::Two)).map(|run| run.parse),
+            solve: Part::BOTH.map(|part| prod(part).map(|run| run.solve)),
+        }
+    }
+
+    /// A part has its star once it gives an answer that isn't known to be wrong
+    pub fn stars(&self) -> usize {
+        self.status
+            .iter()
+            .filter(|s| matches!(s, Status::Pass | Status::Missing))
+            .count()
+    }
+}
+
+/// The worst status of a part over all inputs, a failure anywhere is a failure
+fn part_status(statuses: impl Iterator<Item = Status>) -> Status {
+    let rank = |status: &Status| match status {
+        Status::Fail => 0,
+        Status::Unsolved => 1,
+        Status::Pass => 2,
+        Status::Missing => 3,
+        Status::NoInput => 4,
+    };
+    statuses.min_by_key(rank).unwrap_or(Status::NoInput)
+}
+
+/// Short enough for a table cell, `Debug` of a duration prints every digit
+fn duration_cell(duration: Option<Duration>) -> String {
+    let Some(duration) = duration else {
+        return "-".to_string();
+    };
+    let micros = duration.as_nanos() as f64 / 1e3;
+    if micros < 1e3 {
+        format!("{:.1} µs", micros)
+    } else if micros < 1e6 {
+        format!("{:.1} ms", micros / 1e3)
+    } else {
+        format!("{:.2} s", micros / 1e6)
+    }
+}
+
+const HEADER: [&str; 7] = [
+    "Day", "Stars", "Part 1", "Part 2", "Parse", "Solve 1", "Solve 2",
+];
+
+fn cells(day: &DayReport) -> [String; 7] {
+    [
+        day.day.to_string(),
+        "⭐".repeat(day.stars()),
+        day.status[0].to_string(),
+        day.status[1].to_string(),
+        duration_cell(day.parse),
+        duration_cell(day.solve[0]),
+        duration_cell(day.solve[1]),
+    ]
+}
+
+fn unfinished(days: &[DayReport]) -> Vec<String> {
+    days.iter()
+        .flat_map(|d| {
+            Part::BOTH
+                .into_iter()
+                .filter(|part| d.status[part.number() as usize - 1] == Status::Unsolved)
+                .map(|part| format!("day {} {}", d.day, part))
+        })
+        .collect()
+}
+
+fn total_stars(days: &[DayReport]) -> usize {
+    days.iter().map(DayReport::stars).sum()
+}
+
+const TIMES_NOTE: &str = "Parts are checked against `answers.toml` on every input it has \
+answers for. Times are of a single run on the prod input, `aoc bench` measures them properly.";
+
+pub fn markdown(year: u16, days: &[DayReport]) -> String {
+    let mut md = format!("# Advent of Code {}\n\n", year);
+    md += &format!("{} stars\n\n", total_stars(days));
+    md += &format!("| {} |\n", HEADER.join(" | "));
+    md += "|---:|:---|:---|:---|---:|---:|---:|\n";
+    for day in days {
+        md += &format!("| {} |\n", cells(day).join(" | "));
+    }
+    md += &format!("\n{}\n", TIMES_NOTE);
+    let unfinished = unfinished(days);
+    if !unfinished.is_empty() {
+        md += "\n## Unfinished\n\n";
+        for part in unfinished {
+            md += &format!("- {}\n", part);
+        }
+    }
+    md
+}
+
+pub fn html(year: u16, days: &[DayReport]) -> String {
+    let row = |tag: &str, cells: &[String]| {
+        let cells: String = cells
+            .iter()
+            .map(|c| format!("<{0}>{1}</{0}>", tag, c))
+            .collect();
+        format!("<tr>{}</tr>\n", cells)
+    };
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code {0}</title>\n</head>\n<body>\n<h1>Advent of Code {0}</h1>\n",
+        year
+    );
+    html += &format!("<p>{} stars</p>\n<table>\n", total_stars(days));
+    html += &row("th", &HEADER.map(String::from));
+    for day in days {
+        html += &row("td", &cells(day));
+    }
+    html += "</table>\n";
+    html += &format!("<p>{}</p>\n", TIMES_NOTE.replace('`', ""));
+    let unfinished = unfinished(days);
+    if !unfinished.is_empty() {
+        html += "<h2>Unfinished</h2>\n<ul>\n";
+        for part in unfinished {
+            html += &format!("<li>{}</li>\n", part);
+        }
+        html += "</ul>\n";
+    }
+    html + "</body>\n</html>\n"
+}
+
+/// `aoc report`, runs every day like `aoc verify` and writes the outcome as a table
+pub fn report(year: u16, days: &[&Day], manifest: &Manifest, args: &ReportArgs) -> Result<()> {
+    let checks = check(year, days, manifest);
+    let days: Vec<DayReport> = days
+        .iter()
+        .map(|d| DayReport::new(d.day, &checks))
+        .collect();
+    let path = args
+        .output
+        .clone()
+        .unwrap_or_else(|| crate_dir(year).join("REPORT.md"));
+    fs::write(&path, markdown(year, &days))
+        .with_context(|| format!("couldn't write {}", path.display()))?;
+    println!("Wrote {}", path.display());
+    if args.html {
+        let path = path.with_extension("html");
+        fs::write(&path, html(year, &days))
+            .with_context(|| format!("couldn't write {}", path.display()))?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Answer, Run};
+
+    fn checked(day: u8, input: &str, part: Part, status: Status, solve: u64) -> Check {
+        Check {
+            day,
+            input: input.to_string(),
+            part,
+            status,
+            expected: None,
+            run: matches!(status, Status::Pass | Status::Missing).then(|| Run {
+                answer: Answer::from(1u8),
+                parse: Duration::from_micros(20),
+                solve: Duration::from_micros(solve),
+            }),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn day_rows() {
+        let checks = [
+            checked(1, "prod", Part::One, Status::Missing, 1500),
+            checked(1, "prod", Part::Two, Status::Pass, 2_500_000),
+            checked(1, "test", Part::Two, Status::Fail, 3),
+            checked(2, "prod", Part::One, Status::Pass, 7),
+            checked(2, "prod", Part::Two, Status::Unsolved, 0),
+        ];
+        let days = [DayReport::new(1, &checks), DayReport::new(2, &checks)];
+        assert_eq!(days[0].status, [Status::Missing, Status::Fail]);
+        assert_eq!((days[0].stars(), days[1].stars()), (1, 1));
+        assert_eq!(
+            cells(&days[0])[4..],
+            ["20.0 µs", "1.5 ms", "2.50 s"].map(String::from)
+        );
+        assert_eq!(unfinished(&days), ["day 2 part 2"]);
+
+        let md = markdown(2024, &days);
+        assert!(md.contains("| 2 | ⭐ | pass | unsolved | 20.0 µs | 7.0 µs | - |\n"));
+        assert!(md.ends_with("## Unfinished\n\n- day 2 part 2\n"));
+        assert!(html(2024, &days).contains("<li>day 2 part 2</li>"));
+    }
+
+    #[test]
+    fn days_without_inputs() {
+        let day = DayReport::new(3, &[]);
+        assert_eq!(day.status, [Status::NoInput; 2]);
+        assert_eq!(cells(&day)[1..5], ["", "no input", "no input", "-"]);
+    }
+
+    #[test]
+    fn unfinished_without_inputs() {
+        struct Half;
+
+        impl crate::solution::Solution for Half {
+            type Input = ();
+            type Answer1 = u8;
+            type Answer2 = crate::solution::Unsolved;
+
+            fn parse(_input: &str) -> Result<()> {
+                Ok(())
+            }
+
+            fn part1(_input: &()) -> Result<u8> {
+                Ok(1)
+            }
+        }
+
+        // there is no rust_1999 crate, so no input files either
+        let day = Day::new::<Half>(10);
+        let checks = check(1999, &[&day], &Manifest::default());
+        let days = [DayReport::new(10, &checks)];
+        assert_eq!(days[0].status, [Status::NoInput, Status::Unsolved]);
+        assert!(markdown(1999, &days).ends_with("## Unfinished\n\n- day 10 part 2\n"));
+    }
+}
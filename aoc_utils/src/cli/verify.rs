@@ -0,0 +1,295 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Result};
+
+use super::{print_table, Input};
+use crate::solution::{is_unsolved, Answer, Day, Manifest, Part, Run};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    Pass,
+    /// Wrong answer, an error or a panic, or a part that was solved before isn't anymore
+    Fail,
+    /// Solved but the manifest has no answer to compare with
+    Missing,
+    /// The answer type is `Unsolved` and the manifest has no answer for it, or only a
+    /// pending one. Whether there is an input or not doesn't matter.
+    Unsolved,
+    /// The manifest has an answer but the input file isn't there
+    NoInput,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Unsolved => "unsolved",
+            Status::NoInput => "no input",
+        };
+        f.pad(status)
+    }
+}
+
+/// Outcome of one part of a day on one input
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub status: Status,
+    /// A pending answer counts as expected once the part is solved
+    pub expected: Option<Answer>,
+    /// Only there when the part ran to an answer
+    pub run: Option<Run>,
+    pub error: Option<String>,
+}
+
+/// Runs `days` on `prod` and on every input the manifest has answers for. Examples often
+/// only fit one part, so other inputs only run the parts that have an answer, pending or not. Panics are
+/// caught and count as failures, so one broken day doesn't hide the others.
+pub fn check(year: u16, days: &[&Day], manifest: &Manifest) -> Vec<Check> {
+    let _quiet = QuietPanics::install();
+    days.iter()
+        .flat_map(|day| check_day(year, day, manifest))
+        .collect()
+}
+
+type Hook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+thread_local! {
+    /// Set while `run_caught` runs a day, only those panics are kept quiet
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Keeps the panic hook from printing the panics `run_caught` reports itself, panics of
+/// other threads still go to the previous hook. It's put back when this is dropped.
+struct QuietPanics {
+    previous: Arc<Hook>,
+}
+
+impl QuietPanics {
+    fn install() -> Self {
+        let previous = Arc::new(panic::take_hook());
+        let forward = Arc::clone(&previous);
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                forward(info)
+            }
+        }));
+        QuietPanics { previous }
+    }
+}
+
+impl Drop for QuietPanics {
+    fn drop(&mut self) {
+        let previous = Arc::clone(&self.previous);
+        panic::set_hook(Box::new(move |info| previous(info)));
+    }
+}
+
+fn check_day(year: u16, day: &Day, manifest: &Manifest) -> Vec<Check> {
+    let mut inputs = manifest.inputs(year, day.day);
+    if !inputs.contains(&"prod") {
+        inputs.insert(0, "prod");
+    }
+    let mut checks = Vec::new();
+    for input in inputs {
+        let path = Input::Named(input.to_string()).path(year, day.day);
+        let text = fs::read_to_string(path).ok();
+        for part in Part::BOTH {
+            let expected = manifest.expected(year, day.day, input, part).cloned();
+            let pending = manifest.pending(year, day.day, input, part).cloned();
+            if expected.is_none() && pending.is_none() && input != "prod" {
+                continue;
+            }
+            let solved_before = expected.is_some();
+            let mut check = Check {
+                day: day.day,
+                input: input.to_string(),
+                part,
+                status: Status::NoInput,
+                expected: expected.or(pending),
+                run: None,
+                error: None,
+            };
+            // known from the answer type, so it shows up even without any input
+            if !day.is_solved(part) {
+                if solved_before {
+                    check.status = Status::Fail;
+                    check.error = Some(
+                        "the answer type is `Unsolved`, but the answer isn't pending".to_string(),
+                    );
+                } else {
+                    check.status = Status::Unsolved;
+                }
+                checks.push(check);
+                continue;
+            }
+            let Some(text) = &text else {
+                if check.expected.is_some() {
+                    checks.push(check);
+                }
+                continue;
+            };
+            match run_caught(day, text, part) {
+                Ok(run) => {
+                    check.status = match &check.expected {
+                        Some(expected) if *expected == run.answer => Status::Pass,
+                        Some(_) => Status::Fail,
+                        None => Status::Missing,
+                    };
+                    check.run = Some(run);
+                }
+                Err(e) if is_unsolved(&e) && check.expected.is_none() => {
+                    check.status = Status::Unsolved;
+                }
+                Err(e) => {
+                    check.status = Status::Fail;
+                    check.error = Some(format!("{:#}", e));
+                }
+            }
+            checks.push(check);
+        }
+    }
+    checks
+}
+
+fn run_caught(day: &Day, input: &str, part: Part) -> Result<Run> {
+    QUIET.set(true);
+    let run = panic::catch_unwind(AssertUnwindSafe(|| day.run(input, part)));
+    QUIET.set(false);
+    run.unwrap_or_else(|panic| Err(anyhow!("panicked: {}", panic_message(&panic))))
+}
+
+fn panic_message(panic: &Box<dyn Any + Send>) -> &str {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message
+    } else {
+        "no message"
+    }
+}
+
+/// Art is too tall for a table cell
+pub fn answer_cell(answer: &Answer) -> String {
+    if answer.is_art() {
+        format!("({} lines of art)", answer.to_string().lines().count())
+    } else {
+        answer.to_string()
+    }
+}
+
+/// `aoc verify`, fails when any answer doesn't match the manifest
+pub fn verify(year: u16, days: &[&Day], manifest: &Manifest) -> Result<()> {
+    let checks = check(year, days, manifest);
+    let rows = checks
+        .iter()
+        .map(|c| {
+            vec![
+                c.day.to_string(),
+                c.part.number().to_string(),
+                c.input.clone(),
+                c.status.to_string(),
+                c.run
+                    .as_ref()
+                    .map_or(String::new(), |r| answer_cell(&r.answer)),
+                c.expected.as_ref().map_or(String::new(), answer_cell),
+            ]
+        })
+        .collect();
+    print_table(
+        &["day", "part", "input", "status", "answer", "expected"],
+        rows,
+    );
+    for c in &checks {
+        if let Some(error) = &c.error {
+            println!("day {} {} ({}): {}", c.day, c.part, c.input, error);
+        }
+    }
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    println!(
+        "{} passed, {} failed, {} missing, {} unsolved, {} without input",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing),
+        count(Status::Unsolved),
+        count(Status::NoInput)
+    );
+    if count(Status::Fail) > 0 {
+        bail!("{} answers don't match", count(Status::Fail));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Solution, Unsolved};
+
+    struct Panics;
+
+    impl Solution for Panics {
+        type Input = ();
+        type Answer1 = u8;
+        type Answer2 = Unsolved;
+
+        fn parse(_input: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Result<u8> {
+            panic!("index out of bounds")
+        }
+    }
+
+    #[test]
+    fn panics_are_failures() {
+        let day = Day::new::<Panics>(1);
+        let err = run_caught(&day, "", Part::One).unwrap_err();
+        assert_eq!(err.to_string(), "panicked: index out of bounds");
+        assert!(is_unsolved(&run_caught(&day, "", Part::Two).unwrap_err()));
+    }
+
+    #[test]
+    fn inputs_without_files() {
+        let manifest = Manifest::parse("[1999.1.test]\npart1 = 3").unwrap();
+        let checks = check(1999, &[&Day::new::<Panics>(1)], &manifest);
+        // prod has neither a file nor answers, test only lacks the file
+        let statuses: Vec<_> = checks
+            .iter()
+            .map(|c| (c.input.as_str(), c.part, c.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                ("prod", Part::Two, Status::Unsolved),
+                ("test", Part::One, Status::NoInput)
+            ]
+        );
+    }
+
+    #[test]
+    fn unsolved_parts_with_answers() {
+        let day = Day::new::<Panics>(1);
+        let manifest = Manifest::parse("[pending.1999.1.test]\npart2 = 4").unwrap();
+        let checks = check(1999, &[&day], &manifest);
+        let test = checks.iter().find(|c| c.input == "test").unwrap();
+        assert_eq!(test.status, Status::Unsolved);
+        assert_eq!(test.expected, Some(Answer::from(4u8)));
+
+        // an answer that isn't pending means the part was solved before
+        let manifest = Manifest::parse("[1999.1.test]\npart2 = 4").unwrap();
+        let checks = check(1999, &[&day], &manifest);
+        let test = checks.iter().find(|c| c.input == "test").unwrap();
+        assert_eq!(test.status, Status::Fail);
+        assert!(test.error.is_some());
+    }
+}
@@ -0,0 +1,297 @@
+//! Downloads puzzle inputs into the local `inputs/` directories.
+//! Only built with the `fetch` feature.
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the site as its automation guidelines ask
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "github.com/KaczDev/aoc aoc_utils/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Downloads inputs with a session token, every input is downloaded at most once
+///
+/// Example usage:
+/// ```ignore
+/// let fetched = Fetcher::from_env()?.input(2024, 16, "./inputs")?;
+/// let text = std::fs::read_to_string(fetched.path)?;
+/// ```
+pub struct Fetcher {
+    session: String,
+    base_url: String,
+    user_agent: String,
+}
+
+/// Where an input ended up and whether it was already there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetched {
+    pub path: PathBuf,
+    pub cached: bool,
+}
+
+impl Fetcher {
+    pub fn new(session: impl Into<String>) -> Self {
+        Fetcher {
+            session: session.into(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+        }
+    }
+
+    /// Session from `session_token`, base URL from `AOC_BASE_URL` and
+    /// User-Agent from `AOC_USER_AGENT` when they are set
+    pub fn from_env() -> Result<Self, FetchError> {
+        let mut fetcher = Fetcher::new(session_token()?);
+        if let Ok(url) = env::var("AOC_BASE_URL") {
+            fetcher = fetcher.base_url(url);
+        }
+        if let Ok(agent) = env::var("AOC_USER_AGENT") {
+            fetcher = fetcher.user_agent(agent);
+        }
+        Ok(fetcher)
+    }
+
+    /// Site to download from, without a trailing `/`
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn user_agent(mut self, agent: impl Into<String>) -> Self {
+        self.user_agent = agent.into();
+        self
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    /// Makes sure `dir/dayN.prod` exists, downloading it only if it's missing
+    pub fn input(&self, year: u16, day: u8, dir: impl AsRef<Path>) -> Result<Fetched, FetchError> {
+        if year < 2015 || !(1..=days_in(year)).contains(&day) {
+            return Err(FetchError::NoSuchPuzzle { year, day });
+        }
+        let path = input_path(dir.as_ref(), day);
+        if path.exists() {
+            return Ok(Fetched { path, cached: true });
+        }
+        let body = self.get(&self.input_url(year, day))?;
+        fs::create_dir_all(dir.as_ref())?;
+        // a download that fails half way must not look like a cached input
+        let partial = path.with_extension("part");
+        fs::write(&partial, body)?;
+        fs::rename(&partial, &path)?;
+        Ok(Fetched {
+            path,
+            cached: false,
+        })
+    }
+
+    fn get(&self, url: &str) -> Result<String, FetchError> {
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", &self.user_agent)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => FetchError::Http {
+                    url: url.to_string(),
+                    status,
+                },
+                ureq::Error::Transport(t) => FetchError::Transport(t.to_string()),
+            })?;
+        Ok(response.into_string()?)
+    }
+}
+
+/// Puzzles per year, the calendar was cut down to 12 days from 2025 on
+pub fn days_in(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// `dir/dayN.prod`, the file every `main` reads its real input from
+pub fn input_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{}.prod", day))
+}
+
+/// The session cookie from `AOC_SESSION`, or else from the file named by `AOC_SESSION_FILE`,
+/// defaulting to `~/.config/aoc/session`
+pub fn session_token() -> Result<String, FetchError> {
+    let file = env::var_os("AOC_SESSION_FILE")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config/aoc/session")));
+    read_session(env::var("AOC_SESSION").ok(), file.as_deref())
+}
+
+fn read_session(var: Option<String>, file: Option<&Path>) -> Result<String, FetchError> {
+    let token = match (var, file) {
+        (Some(token), _) => token,
+        (None, Some(file)) if file.exists() => fs::read_to_string(file)?,
+        _ => String::new(),
+    };
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(FetchError::MissingSession);
+    }
+    Ok(token.to_string())
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    NoSuchPuzzle {
+        year: u16,
+        day: u8,
+    },
+    /// The server answered with an error status, usually an expired session
+    Http {
+        url: String,
+        status: u16,
+    },
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "no session token, set AOC_SESSION or save it in ~/.config/aoc/session"
+            ),
+            FetchError::NoSuchPuzzle { year, day } => {
+                write!(f, "there is no puzzle for {} day {}", year, day)
+            }
+            FetchError::Http { url, status } => write!(f, "GET {} returned {}", url, status),
+            FetchError::Transport(e) => write!(f, "request failed: {}", e),
+            FetchError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Answers every request with `status` and `body`, keeping the request heads
+    fn stub_server(status: &'static str, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut head = String::new();
+                let mut reader = BufReader::new(&stream);
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                seen.lock().unwrap().push(head);
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_fetch_{}_{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once() {
+        let (url, requests) = stub_server("200 OK", "1\n2\n3\n");
+        let dir = temp_dir("once");
+        let fetcher = Fetcher::new("abc").base_url(format!("{}/", url));
+
+        let first = fetcher.input(2024, 7, &dir).unwrap();
+        assert_eq!(first.path, dir.join("day7.prod"));
+        assert!(!first.cached);
+        assert_eq!(fs::read_to_string(&first.path).unwrap(), "1\n2\n3\n");
+        let second = fetcher.input(2024, 7, &dir).unwrap();
+        assert!(second.cached);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/7/input HTTP/1.1"));
+        assert!(requests[0].contains("Cookie: session=abc"));
+        assert!(requests[0].contains(&format!("User-Agent: {}", DEFAULT_USER_AGENT)));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn errors_leave_nothing_behind() {
+        let (url, _) = stub_server("400 Bad Request", "Please log in");
+        let dir = temp_dir("error");
+        let fetcher = Fetcher::new("expired").base_url(url);
+        let err = fetcher.input(2024, 1, &dir).unwrap_err();
+        assert!(matches!(err, FetchError::Http { status: 400, .. }));
+        assert!(!input_path(&dir, 1).exists());
+        assert!(matches!(
+            fetcher.input(2024, 26, &dir),
+            Err(FetchError::NoSuchPuzzle { .. })
+        ));
+        assert!(matches!(
+            fetcher.input(2025, 13, &dir),
+            Err(FetchError::NoSuchPuzzle { .. })
+        ));
+    }
+
+    #[test]
+    fn session_sources() {
+        let file = env::temp_dir().join(format!("aoc_session_{}", std::process::id()));
+        fs::write(&file, "from-file\n").unwrap();
+        assert_eq!(
+            read_session(Some(" from-env ".to_string()), Some(&file)).unwrap(),
+            "from-env"
+        );
+        assert_eq!(read_session(None, Some(&file)).unwrap(), "from-file");
+        fs::remove_file(&file).unwrap();
+        assert!(matches!(
+            read_session(None, Some(&file)),
+            Err(FetchError::MissingSession)
+        ));
+    }
+}
@@ -0,0 +1,235 @@
+use std::fs;
+use std::io;
+use std::ops::{Index, IndexMut};
+use std::path::Path;
+
+use super::{Direction, Grid, ParseGridError, Point};
+
+/// Grid over the raw bytes of an input file. Rows keep their trailing `\n`, so the
+/// stride is `width + 1` and the file is used as loaded without copying.
+/// One byte per cell instead of the four of a `char`, meant for hot simulation loops.
+/// The newlines double as the border when looking at neighbours, so no cell may be set to `\n`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ByteGrid {
+    bytes: Vec<u8>,
+    width: usize,
+    height: usize,
+}
+
+impl ByteGrid {
+    /// Reads the whole file in one go
+    pub fn read(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_bytes(fs::read(path)?).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Takes ownership of the bytes of the lines, a missing final newline is added
+    pub fn from_bytes(mut bytes: Vec<u8>) -> Result<Self, ParseGridError> {
+        if bytes.last().is_some_and(|&b| b != b'\n') {
+            bytes.push(b'\n');
+        }
+        let width = bytes.iter().position(|&b| b == b'\n').unwrap_or(0);
+        let stride = width + 1;
+        for (r, line) in bytes.split_inclusive(|&b| b == b'\n').enumerate() {
+            if line.len() != stride {
+                return Err(ParseGridError::Ragged {
+                    row: r,
+                    expected: width,
+                    found: line.len() - 1,
+                });
+            }
+        }
+        let height = bytes.len() / stride;
+        Ok(ByteGrid {
+            bytes,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn stride(&self) -> usize {
+        self.width + 1
+    }
+
+    pub fn in_bounds(&self, (r, c): Point) -> bool {
+        r < self.height && c < self.width
+    }
+
+    pub fn get(&self, p: Point) -> Option<u8> {
+        self.in_bounds(p).then(|| self.bytes[self.index_of(p)])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut u8> {
+        if self.in_bounds(p) {
+            let i = self.index_of(p);
+            Some(&mut self.bytes[i])
+        } else {
+            None
+        }
+    }
+
+    /// Offset of the cell in the underlying bytes, newlines included
+    pub fn index_of(&self, (r, c): Point) -> usize {
+        r * self.stride() + c
+    }
+
+    pub fn point_of(&self, i: usize) -> Point {
+        (i / self.stride(), i % self.stride())
+    }
+
+    /// Row `r` without its newline
+    pub fn row(&self, r: usize) -> &[u8] {
+        let start = r * self.stride();
+        &self.bytes[start..start + self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[u8]> + '_ {
+        (0..self.height).map(|r| self.row(r))
+    }
+
+    /// All cells in row-major order together with their position
+    pub fn iter(&self) -> impl Iterator<Item = (Point, u8)> + '_ {
+        self.rows()
+            .enumerate()
+            .flat_map(|(r, row)| row.iter().enumerate().map(move |(c, &b)| ((r, c), b)))
+    }
+
+    /// Position of the first cell equal to `needle`
+    pub fn find(&self, needle: u8) -> Option<Point> {
+        self.bytes
+            .iter()
+            .position(|&b| b == needle)
+            .map(|i| self.point_of(i))
+    }
+
+    /// The file as it was read, newlines included
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Neighbour of `p` in `dir` if it is inside the grid
+    pub fn step(&self, p: Point, dir: Direction) -> Option<Point> {
+        dir.checked_move_point(p).filter(|&n| self.in_bounds(n))
+    }
+
+    /// In-bounds neighbours of `p` in the given directions
+    pub fn neighbors_with<'a>(
+        &'a self,
+        p: Point,
+        dirs: &'a [Direction],
+    ) -> impl Iterator<Item = (Direction, Point, u8)> + 'a {
+        let i = self.index_of(p) as isize;
+        let stride = self.stride() as isize;
+        dirs.iter().filter_map(move |&dir| {
+            let d = dir.resolve();
+            // stepping off the left or right edge lands on a newline, so only the
+            // ends of the buffer need a bounds check
+            let n = usize::try_from(i + d.r * stride + d.c).ok()?;
+            match self.bytes.get(n) {
+                Some(&b) if b != b'\n' => Some((dir, self.point_of(n), b)),
+                _ => None,
+            }
+        })
+    }
+
+    /// In-bounds neighbours of `p` going up, down, left and right
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = (Direction, Point, u8)> + '_ {
+        self.neighbors_with(p, &super::STRAIGHT)
+    }
+
+    /// In-bounds neighbours of `p` including the diagonals
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = (Direction, Point, u8)> + '_ {
+        self.neighbors_with(p, &super::ALL)
+    }
+
+    /// Copies the cells into a `Grid`, for the helpers that only exist there
+    pub fn to_grid(&self) -> Grid<u8> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.rows().flatten().copied().collect(),
+        )
+    }
+}
+
+impl Index<Point> for ByteGrid {
+    type Output = u8;
+
+    fn index(&self, p: Point) -> &u8 {
+        assert!(
+            self.in_bounds(p),
+            "Point {:?} out of range {}x{}",
+            p,
+            self.height,
+            self.width
+        );
+        &self.bytes[self.index_of(p)]
+    }
+}
+
+impl IndexMut<Point> for ByteGrid {
+    fn index_mut(&mut self, p: Point) -> &mut u8 {
+        assert!(
+            self.in_bounds(p),
+            "Point {:?} out of range {}x{}",
+            p,
+            self.height,
+            self.width
+        );
+        let i = self.index_of(p);
+        &mut self.bytes[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_keep_their_newline() {
+        let grid = ByteGrid::from_bytes(b"..@\n@#.".to_vec()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.as_bytes(), b"..@\n@#.\n");
+        assert_eq!(grid.row(1), b"@#.");
+        assert_eq!(grid[(0, 2)], b'@');
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.find(b'#'), Some((1, 1)));
+        assert_eq!(grid.to_grid(), "..@\n@#.".parse::<Grid<u8>>().unwrap());
+    }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        assert_eq!(
+            ByteGrid::from_bytes(b"abc\nab\n".to_vec()),
+            Err(ParseGridError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+        let empty = ByteGrid::from_bytes(Vec::new()).unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+    }
+
+    #[test]
+    fn neighbours_and_steps() {
+        let mut grid = ByteGrid::from_bytes(b"abc\ndef\nghi\n".to_vec()).unwrap();
+        let n4: Vec<u8> = grid.neighbors4((0, 0)).map(|(_, _, b)| b).collect();
+        assert_eq!(n4, b"bd");
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        // the edges don't wrap around into the next or previous row
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+        assert_eq!(grid.step((0, 2), Direction::Right), None);
+        assert_eq!(grid.step((0, 2), Direction::Down), Some((1, 2)));
+        grid[(2, 2)] = b'#';
+        assert_eq!(grid.row(2), b"gh#");
+    }
+}
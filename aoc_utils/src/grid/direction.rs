@@ -0,0 +1,198 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::{Point, Vec2};
+
+#[derive(Copy, Debug, Clone, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+    RightUp,
+    RightDown,
+    LeftUp,
+    LeftDown,
+}
+
+/// Every direction going clockwise from `Up`, each step is 45°
+const CLOCKWISE: [Direction; 8] = [
+    Direction::Up,
+    Direction::RightUp,
+    Direction::Right,
+    Direction::RightDown,
+    Direction::Down,
+    Direction::LeftDown,
+    Direction::Left,
+    Direction::LeftUp,
+];
+
+impl Direction {
+    pub const fn all_straight() -> [Direction; 4] {
+        [
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+            Direction::Up,
+        ]
+    }
+    pub const fn all_diagonals() -> [Direction; 4] {
+        [
+            Direction::RightUp,
+            Direction::RightDown,
+            Direction::LeftUp,
+            Direction::LeftDown,
+        ]
+    }
+    /// All eight directions, clockwise from `Up`
+    pub const fn all() -> [Direction; 8] {
+        CLOCKWISE
+    }
+    /// Unit (dr, dc) offset of the direction
+    pub fn resolve(self) -> Vec2 {
+        match self {
+            Direction::Left => Vec2::new(0, -1),
+            Direction::Down => Vec2::new(1, 0),
+            Direction::Right => Vec2::new(0, 1),
+            Direction::Up => Vec2::new(-1, 0),
+            Direction::RightUp => Vec2::new(-1, 1),
+            Direction::RightDown => Vec2::new(1, 1),
+            Direction::LeftUp => Vec2::new(-1, -1),
+            Direction::LeftDown => Vec2::new(1, -1),
+        }
+    }
+    pub fn is_diagonal(&self) -> bool {
+        matches!(
+            self,
+            Direction::RightUp | Direction::RightDown | Direction::LeftUp | Direction::LeftDown
+        )
+    }
+
+    /// Rotates by `steps` * 45°, positive steps go clockwise
+    fn rotate(&self, steps: isize) -> Direction {
+        let i = CLOCKWISE.iter().position(|d| d == self).unwrap() as isize;
+        CLOCKWISE[(i + steps).rem_euclid(8) as usize]
+    }
+    /// 90° counter clockwise
+    pub fn rotate_counter_clockwise(&self) -> Direction {
+        self.rotate(-2)
+    }
+    /// 90° clockwise
+    pub fn rotate_clockwise(&self) -> Direction {
+        self.rotate(2)
+    }
+    /// 45° counter clockwise
+    pub fn rotate_counter_clockwise_45(&self) -> Direction {
+        self.rotate(-1)
+    }
+    /// 45° clockwise
+    pub fn rotate_clockwise_45(&self) -> Direction {
+        self.rotate(1)
+    }
+    /// 180°
+    pub fn opposite(&self) -> Direction {
+        self.rotate(4)
+    }
+
+    /// Add direction to Point, `None` if the result would underflow
+    pub fn checked_move_point(self, p: Point) -> Option<Point> {
+        (Vec2::from(p) + self.resolve()).to_point()
+    }
+
+    /// Add direction to Point, doesn't check for underflows. Will panic if underflow happens
+    pub fn move_point(self, p: Point) -> Point {
+        self.checked_move_point(p)
+            .unwrap_or_else(|| panic!("Couldn't move {:?} {:?}", p, self))
+    }
+}
+
+/// Arrows `^>v<` and compass letters `NESW`
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' => Ok(Direction::Up),
+            '>' | 'E' => Ok(Direction::Right),
+            'v' | 'S' => Ok(Direction::Down),
+            '<' | 'W' => Ok(Direction::Left),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+/// Everything `TryFrom<char>` accepts plus the diagonal compass points `NE`, `SE`, `SW`, `NW`
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction::RightUp),
+            "SE" => Ok(Direction::RightDown),
+            "SW" => Ok(Direction::LeftDown),
+            "NW" => Ok(Direction::LeftUp),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Direction::try_from(c),
+                    _ => Err(ParseDirectionError(s.to_string())),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(pub String);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a direction", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotations() {
+        for d in Direction::all() {
+            assert_eq!(d.rotate_clockwise().rotate_counter_clockwise(), d);
+            assert_eq!(
+                d.rotate_clockwise_45().rotate_clockwise_45(),
+                d.rotate_clockwise()
+            );
+            assert_eq!(d.rotate_clockwise().rotate_clockwise(), d.opposite());
+            assert_eq!(d.opposite().resolve(), -d.resolve());
+        }
+        assert_eq!(Direction::Up.rotate_clockwise(), Direction::Right);
+        assert_eq!(Direction::LeftUp.rotate_clockwise(), Direction::RightUp);
+        assert_eq!(
+            Direction::RightUp.rotate_counter_clockwise_45(),
+            Direction::Up
+        );
+        assert_eq!(Direction::LeftDown.opposite(), Direction::RightUp);
+    }
+
+    #[test]
+    fn sets() {
+        assert!(Direction::all_diagonals()
+            .iter()
+            .all(Direction::is_diagonal));
+        assert!(!Direction::all_straight().iter().any(Direction::is_diagonal));
+        assert_eq!(Direction::all().len(), 8);
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Direction::try_from('^'), Ok(Direction::Up));
+        assert_eq!(Direction::try_from('W'), Ok(Direction::Left));
+        assert_eq!("v".parse(), Ok(Direction::Down));
+        assert_eq!("NW".parse(), Ok(Direction::LeftUp));
+        assert!(Direction::try_from('x').is_err());
+        assert!("NN".parse::<Direction>().is_err());
+    }
+}
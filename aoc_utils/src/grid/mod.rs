@@ -1,38 +1,392 @@
-pub type Grid<T> = Vec<Vec<T>>;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+mod bytes;
+mod direction;
+mod regions;
+mod render;
+mod sparse;
+mod transform;
+mod vec2;
+
+pub use bytes::ByteGrid;
+pub use direction::{Direction, ParseDirectionError};
+pub use regions::{Region, Regions};
+pub use render::{write_pbm, write_ppm, Color, Renderer};
+pub use sparse::SparseGrid;
+pub use transform::GridView;
+pub use vec2::Vec2;
 
 /// (r, c)
 pub type Point = (usize, usize);
 
+/// Rectangular grid stored row-major in one contiguous `Vec`.
+/// Cells are addressed with a `Point` (r, c).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from row-major cells, panics if `cells.len() != width * height`
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "Grid of {}x{} needs {} cells, got {}",
+            width,
+            height,
+            width * height,
+            cells.len()
+        );
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Grid of `width` x `height` with every cell set to `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Flattens nested rows, errors if the rows are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseGridError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (r, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseGridError::Ragged {
+                    row: r,
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+        Ok(Grid::new(width, height, cells))
+    }
+
+    /// Parses every character of every line with `TryFrom<char>`
+    pub fn from_lines<I, S>(lines: I) -> Result<Self, ParseGridError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        T: TryFrom<char>,
+    {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for (r, line) in lines.into_iter().enumerate() {
+            let line = line.as_ref();
+            let start = cells.len();
+            for (c, ch) in line.chars().enumerate() {
+                let cell = T::try_from(ch)
+                    .map_err(|_| ParseGridError::InvalidCell { point: (r, c), ch })?;
+                cells.push(cell);
+            }
+            let found = cells.len() - start;
+            if r == 0 {
+                width = found;
+            } else if found != width {
+                return Err(ParseGridError::Ragged {
+                    row: r,
+                    expected: width,
+                    found,
+                });
+            }
+            height += 1;
+        }
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (r, c): Point) -> bool {
+        r < self.height && c < self.width
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        if self.in_bounds(p) {
+            Some(&self.cells[self.index_of(p)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.in_bounds(p) {
+            let i = self.index_of(p);
+            Some(&mut self.cells[i])
+        } else {
+            None
+        }
+    }
+
+    /// Offset of (r, c) in the flat storage, doesn't check bounds
+    pub fn index_of(&self, (r, c): Point) -> usize {
+        r * self.width + c
+    }
+
+    /// Inverse of `index_of`
+    pub fn point_of(&self, i: usize) -> Point {
+        (i / self.width, i % self.width)
+    }
+
+    /// Row `r` as a slice, panics if out of range
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, r: usize) -> &mut [T] {
+        &mut self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    /// Column `c` top to bottom, panics if out of range
+    pub fn column(&self, c: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(c < self.width, "Column {} out of range {}", c, self.width);
+        self.cells.iter().skip(c).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        // chunks_exact panics on 0, an empty grid has no rows anyway
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// All cells in row-major order together with their position
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point_of(i), cell))
+    }
+
+    /// The underlying row-major storage
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// First position (row-major) holding `needle`
+    pub fn find(&self, needle: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|cell| cell == needle)
+            .map(|i| self.point_of(i))
+    }
+
+    /// In-bounds neighbours of `p` in the given directions
+    pub fn neighbors_with<'a>(
+        &'a self,
+        p: Point,
+        dirs: &'a [Direction],
+    ) -> impl Iterator<Item = (Direction, Point, &'a T)> + 'a {
+        dirs.iter().filter_map(move |&dir| {
+            let n = dir.checked_move_point(p).filter(|&n| self.in_bounds(n))?;
+            Some((dir, n, &self[n]))
+        })
+    }
+
+    /// In-bounds neighbours of `p` going up, down, left and right
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = (Direction, Point, &T)> + '_ {
+        self.neighbors_with(p, &STRAIGHT)
+    }
+
+    /// In-bounds neighbours of `p` including the diagonals
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = (Direction, Point, &T)> + '_ {
+        self.neighbors_with(p, &ALL)
+    }
+
+    /// Neighbours of `p` in the given directions where leaving one edge enters on the opposite
+    /// one, so every direction yields a cell
+    pub fn neighbors_wrapping<'a>(
+        &'a self,
+        p: Point,
+        dirs: &'a [Direction],
+    ) -> impl Iterator<Item = (Direction, Point, &'a T)> + 'a {
+        dirs.iter().map(move |&dir| {
+            let n = (Vec2::from(p) + dir.resolve()).wrap(self.height, self.width);
+            (dir, n, &self[n])
+        })
+    }
+
+    /// New grid of the same size with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        assert!(
+            self.in_bounds(p),
+            "Point {:?} out of range {}x{}",
+            p,
+            self.height,
+            self.width
+        );
+        &self.cells[self.index_of(p)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        assert!(
+            self.in_bounds(p),
+            "Point {:?} out of range {}x{}",
+            p,
+            self.height,
+            self.width
+        );
+        let i = self.index_of(p);
+        &mut self.cells[i]
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::from_lines(s.lines())
+    }
+}
+
+/// Parses `text` line by line with `cell` while pulling out the position of every marker,
+/// given as (marker, floor) pairs. Every marker has to appear exactly once and its cell
+/// is parsed as `floor` instead, the positions come back in the order of `markers`.
+///
+/// Example usage:
+/// ```
+/// use aoc_utils::grid::parse_grid;
+///
+/// let (walls, [start, end]) = parse_grid("#S.\n#.E", [('S', '.'), ('E', '.')], |c| c == '#')?;
+/// assert_eq!((start, end), ((0, 1), (1, 2)));
+/// assert!(!walls[start]);
+/// # Ok::<(), aoc_utils::grid::ParseGridError>(())
+/// ```
+pub fn parse_grid<T, const N: usize>(
+    text: &str,
+    markers: [(char, char); N],
+    cell: impl Fn(char) -> T,
+) -> Result<(Grid<T>, [Point; N]), ParseGridError> {
+    let mut found: [Option<Point>; N] = [None; N];
+    let mut rows = Vec::new();
+    for (r, line) in text.lines().enumerate() {
+        let mut row = Vec::with_capacity(line.len());
+        for (c, ch) in line.chars().enumerate() {
+            let ch = match markers.iter().position(|&(m, _)| m == ch) {
+                Some(i) => {
+                    if let Some(first) = found[i] {
+                        return Err(ParseGridError::DuplicateMarker {
+                            ch,
+                            first,
+                            second: (r, c),
+                        });
+                    }
+                    found[i] = Some((r, c));
+                    markers[i].1
+                }
+                None => ch,
+            };
+            row.push(cell(ch));
+        }
+        rows.push(row);
+    }
+    let grid = Grid::from_rows(rows)?;
+    let mut points = [(0, 0); N];
+    for (i, p) in found.into_iter().enumerate() {
+        points[i] = p.ok_or(ParseGridError::MissingMarker(markers[i].0))?;
+    }
+    Ok((grid, points))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// Row `row` has a different length than the first row
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The character couldn't be converted into a cell
+    InvalidCell {
+        point: Point,
+        ch: char,
+    },
+    MissingMarker(char),
+    DuplicateMarker {
+        ch: char,
+        first: Point,
+        second: Point,
+    },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(f, "row {} has {} cells, expected {}", row, found, expected),
+            ParseGridError::InvalidCell { point, ch } => {
+                write!(f, "invalid cell {:?} at {:?}", ch, point)
+            }
+            ParseGridError::MissingMarker(ch) => write!(f, "marker {:?} not found", ch),
+            ParseGridError::DuplicateMarker { ch, first, second } => {
+                write!(f, "marker {:?} found at {:?} and {:?}", ch, first, second)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
 /// Checks if the position (r,c) + (dr,dc) would lead to out of range in the grid
-pub fn will_be_oob<T>(grid: &Grid<T>, (r, c): Point, direction: Direction) -> bool {
-    let (dr, dc) = direction.resolve();
-    let r = r.checked_add_signed(dr);
-    let c = c.checked_add_signed(dc);
-    r.is_none() || c.is_none() || r.unwrap() >= grid.len() || c.unwrap() >= grid[0].len()
+pub fn will_be_oob<T>(grid: &Grid<T>, p: Point, direction: Direction) -> bool {
+    direction
+        .checked_move_point(p)
+        .is_none_or(|p| !grid.in_bounds(p))
 }
 
 /// Unchecked access to grid, panics if out of range
-pub fn get_element<T>(grid: &Grid<T>, (r, c): Point) -> &T {
-    &grid[r][c]
+pub fn get_element<T>(grid: &Grid<T>, p: Point) -> &T {
+    &grid[p]
 }
 
 /// Returns a tuple of found needle (row, column)
 /// panics otherwise
 pub fn find_element<T: std::cmp::PartialEq + std::fmt::Debug>(grid: &Grid<T>, needle: T) -> Point {
-    for r in 0..grid.len() {
-        for c in 0..grid[0].len() {
-            if grid[r][c] == needle {
-                return (r, c);
-            }
-        }
-    }
-    panic!("COULDNT FIND ELEMENT {:?}", needle);
+    grid.find(&needle)
+        .unwrap_or_else(|| panic!("COULDNT FIND ELEMENT {:?}", needle))
 }
 
 /// Prints the grid in between newlines with Debug trait
 pub fn print_grid_debug<T: std::fmt::Debug>(grid: &Grid<T>) {
     println!();
-    for r in grid {
+    for r in grid.rows() {
         for c in r {
             print!("{:?}", c);
         }
@@ -44,7 +398,7 @@ pub fn print_grid_debug<T: std::fmt::Debug>(grid: &Grid<T>) {
 /// Prints the grid in between newlines
 pub fn print_grid<T: std::fmt::Display>(grid: &Grid<T>) {
     println!();
-    for r in grid {
+    for r in grid.rows() {
         for c in r {
             print!("{}", c);
         }
@@ -56,14 +410,12 @@ pub fn print_grid<T: std::fmt::Display>(grid: &Grid<T>) {
 /// Prints the grid with rows and columns
 pub fn print_grid_with_rows<T: std::fmt::Display>(grid: &Grid<T>) {
     println!();
-    let mut rc = 0;
-    for r in grid {
+    for (rc, r) in grid.rows().enumerate() {
         print!("{}\t", rc);
         for c in r {
             print!("{}", c);
         }
         println!();
-        rc += 1;
     }
     println!();
 }
@@ -72,79 +424,109 @@ pub fn add_points((cur_row, cur_col): Point, (dr, dc): Point) -> Point {
     (cur_row + dr, cur_col + dc)
 }
 
-#[derive(Copy, Debug, Clone, Eq, Hash, PartialEq)]
-pub enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-    RighUp,
-    RightDown,
-    LeftUp,
-    LeftDown,
-}
-
-impl Direction {
-    pub fn all_straight() -> [Direction; 4] {
-        [
-            Direction::Right,
-            Direction::Down,
-            Direction::Left,
-            Direction::Up,
-        ]
-    }
-    pub fn all_diagonals() -> [Direction; 8] {
-        [
-            Direction::RighUp,
-            Direction::RightDown,
-            Direction::LeftUp,
-            Direction::LeftDown,
-            Direction::Right,
-            Direction::Down,
-            Direction::Left,
-            Direction::Up,
-        ]
-    }
-    pub fn resolve(self) -> (isize, isize) {
-        match self {
-            Direction::Left => (0, -1),
-            Direction::Down => (1, 0),
-            Direction::Right => (0, 1),
-            Direction::Up => (-1, 0),
-            Direction::RighUp => (-1, 1),
-            Direction::RightDown => (1, 1),
-            Direction::LeftUp => (-1, -1),
-            Direction::LeftDown => (1, -1),
-        }
+const STRAIGHT: [Direction; 4] = Direction::all_straight();
+const ALL: [Direction; 8] = Direction::all();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_index() {
+        let grid: Grid<char> = "ab.\n.S#".parse().unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(1, 1)], 'S');
+        assert_eq!(grid.get((1, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.find(&'S'), Some((1, 1)));
+        assert_eq!(grid.row(0), &['a', 'b', '.']);
+        assert_eq!(grid.column(2).collect::<String>(), ".#");
     }
-    pub fn rotate_counter_clockwise(&self) -> Direction {
-        match self {
-            Direction::Up => Direction::Left,
-            Direction::Right => Direction::Up,
-            Direction::Down => Direction::Right,
-            Direction::Left => Direction::Down,
-            _ => todo!(),
-        }
+
+    #[test]
+    fn ragged_lines_are_rejected() {
+        let err = "abc\nab".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseGridError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            }
+        );
     }
-    pub fn rotate_clockwise(&self) -> Direction {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-            _ => todo!(),
-        }
+
+    #[test]
+    fn non_ascii_is_rejected_for_bytes() {
+        let err = Grid::<u8>::from_lines(["ab", "a€"]).unwrap_err();
+        assert_eq!(
+            err,
+            ParseGridError::InvalidCell {
+                point: (1, 1),
+                ch: '€'
+            }
+        );
+    }
+
+    #[test]
+    fn index_mut_and_oob() {
+        let mut grid = Grid::filled(2, 3, 0);
+        grid[(2, 1)] = 7;
+        assert_eq!(grid.cells(), &[0, 0, 0, 0, 0, 7]);
+        assert!(will_be_oob(&grid, (2, 1), Direction::Down));
+        assert!(will_be_oob(&grid, (0, 0), Direction::Left));
+        assert!(!will_be_oob(&grid, (1, 1), Direction::Up));
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid: Grid<char> = "abc\ndef\nghi".parse().unwrap();
+        let corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+        assert_eq!(
+            corner,
+            vec![
+                (Direction::Right, (0, 1), &'b'),
+                (Direction::Down, (1, 0), &'d')
+            ]
+        );
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors4((2, 1)).count(), 3);
+        let diagonals: String = grid
+            .neighbors_with((2, 2), &[Direction::LeftUp, Direction::RightDown])
+            .map(|(_, _, &c)| c)
+            .collect();
+        assert_eq!(diagonals, "e");
+    }
+
+    #[test]
+    fn neighbors_wrap_around() {
+        let grid: Grid<char> = "abc\ndef\nghi".parse().unwrap();
+        let wrapped: Vec<_> = grid
+            .neighbors_wrapping((0, 0), &[Direction::Up, Direction::Left, Direction::LeftUp])
+            .map(|(_, p, &c)| (p, c))
+            .collect();
+        assert_eq!(wrapped, vec![((2, 0), 'g'), ((0, 2), 'c'), ((2, 2), 'i')]);
     }
 
-    /// Add direction to Point, doesn't check for underflows. Will panic if underflow happens
-    pub fn move_point(self, (cur_row, cur_col): Point) -> Point {
-        let (dr, dc) = self.resolve();
-        let cur_row = cur_row
-            .checked_add_signed(dr)
-            .expect(&format!("Couldn't add {} to {}", dr, cur_row));
-        let cur_col = cur_col
-            .checked_add_signed(dc)
-            .expect(&format!("Couldn't add {} to {}", dc, cur_col));
-        (cur_row, cur_col)
+    #[test]
+    fn markers_are_extracted() {
+        let (grid, [robot]) = parse_grid("#..\n.@#", [('@', '.')], |c| c).unwrap();
+        assert_eq!(robot, (1, 1));
+        assert_eq!(grid.find(&'@'), None);
+        assert_eq!(grid[robot], '.');
+        assert_eq!(
+            parse_grid("S.\n.E", [('S', '.'), ('^', '.')], |c| c),
+            Err(ParseGridError::MissingMarker('^'))
+        );
+        assert_eq!(
+            parse_grid("S.\n.S", [('S', '.')], |c| c),
+            Err(ParseGridError::DuplicateMarker {
+                ch: 'S',
+                first: (0, 0),
+                second: (1, 1)
+            })
+        );
     }
 }
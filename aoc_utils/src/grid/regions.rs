@@ -0,0 +1,203 @@
+use super::{Direction, Grid, Point};
+
+/// Connected group of cells found by `Grid::regions`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// Label of the region in `Regions::labels`
+    pub id: usize,
+    /// Every cell of the region, the first one is the top-left-most
+    pub cells: Vec<Point>,
+    /// Number of cell edges that border another region or the outside of the grid
+    pub perimeter: usize,
+    /// Number of straight fences around the region, equal to the number of corners
+    pub sides: usize,
+    /// Top-left and bottom-right cell of the smallest rectangle containing the region
+    pub bounds: (Point, Point),
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// Connected-component labelling of a grid
+#[derive(Debug, Clone)]
+pub struct Regions {
+    /// Region id of every cell, `None` for cells that were excluded
+    pub labels: Grid<Option<usize>>,
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Region the cell belongs to
+    pub fn region_of(&self, p: Point) -> Option<&Region> {
+        self.labels[p].map(|id| &self.regions[id])
+    }
+}
+
+impl<T> Grid<T> {
+    /// Regions of 4-connected cells holding equal values
+    pub fn regions(&self) -> Regions
+    where
+        T: PartialEq,
+    {
+        self.label_regions(|_| true, |a, b| a == b)
+    }
+
+    /// Regions of 4-connected cells for which `include` holds, other cells are left unlabelled
+    pub fn regions_where(&self, include: impl Fn(&T) -> bool) -> Regions {
+        self.label_regions(include, |_, _| true)
+    }
+
+    /// Regions where neighbouring cells are joined when `connected(a, b)` holds
+    pub fn regions_by(&self, connected: impl Fn(&T, &T) -> bool) -> Regions {
+        self.label_regions(|_| true, connected)
+    }
+
+    /// Cells reachable from `start` moving between 4-connected cells for which
+    /// `connected(from, to)` holds, in visiting order
+    pub fn flood_fill(&self, start: Point, connected: impl Fn(&T, &T) -> bool) -> Vec<Point> {
+        let mut seen = Grid::filled(self.width, self.height, false);
+        self.flood(start, &mut seen, &connected)
+    }
+
+    fn flood(
+        &self,
+        start: Point,
+        seen: &mut Grid<bool>,
+        connected: &impl Fn(&T, &T) -> bool,
+    ) -> Vec<Point> {
+        // explicit stack, big regions would overflow a recursive dfs
+        let mut stack = vec![start];
+        let mut cells = Vec::new();
+        seen[start] = true;
+        while let Some(p) = stack.pop() {
+            cells.push(p);
+            for (_, n, cell) in self.neighbors4(p) {
+                if !seen[n] && connected(&self[p], cell) {
+                    seen[n] = true;
+                    stack.push(n);
+                }
+            }
+        }
+        cells
+    }
+
+    fn label_regions(
+        &self,
+        include: impl Fn(&T) -> bool,
+        connected: impl Fn(&T, &T) -> bool,
+    ) -> Regions {
+        let mut seen = self.map(|cell| !include(cell));
+        let mut labels = Grid::filled(self.width, self.height, None);
+        let mut regions = Vec::new();
+        let joined = |a: &T, b: &T| include(b) && connected(a, b);
+        for i in 0..self.cells.len() {
+            let start = self.point_of(i);
+            if seen[start] {
+                continue;
+            }
+            let id = regions.len();
+            let cells = self.flood(start, &mut seen, &joined);
+            for &p in &cells {
+                labels[p] = Some(id);
+            }
+            regions.push(Region {
+                id,
+                cells,
+                perimeter: 0,
+                sides: 0,
+                bounds: (start, start),
+            });
+        }
+        for region in regions.iter_mut() {
+            measure(region, &labels);
+        }
+        Regions { labels, regions }
+    }
+}
+
+/// Fills in perimeter, sides and bounds once every cell is labelled
+fn measure(region: &mut Region, labels: &Grid<Option<usize>>) {
+    let inside = |p: Point, dir: Direction| {
+        dir.checked_move_point(p)
+            .and_then(|n| labels.get(n))
+            .is_some_and(|&label| label == Some(region.id))
+    };
+    let (mut min, mut max) = region.bounds;
+    for &p in &region.cells {
+        min = (min.0.min(p.0), min.1.min(p.1));
+        max = (max.0.max(p.0), max.1.max(p.1));
+        for dir in Direction::all_straight() {
+            if !inside(p, dir) {
+                region.perimeter += 1;
+            }
+            // every corner of the outline is counted once from the cell that owns it
+            let side = dir.rotate_clockwise();
+            let convex = !inside(p, dir) && !inside(p, side);
+            let concave =
+                inside(p, dir) && inside(p, side) && !inside(p, dir.rotate_clockwise_45());
+            if convex || concave {
+                region.sides += 1;
+            }
+        }
+    }
+    region.cells.sort();
+    region.bounds = (min, max);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn garden_regions() {
+        let grid: Grid<char> = "AAAA\nBBCD\nBBCC\nEEEC".parse().unwrap();
+        let regions = grid.regions();
+        let metrics: Vec<_> = regions
+            .regions
+            .iter()
+            .map(|r| (grid[r.cells[0]], r.area(), r.perimeter, r.sides))
+            .collect();
+        assert_eq!(
+            metrics,
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+        assert_eq!(regions.region_of((3, 3)).unwrap().bounds, ((1, 2), (3, 3)));
+    }
+
+    #[test]
+    fn enclosed_regions() {
+        let grid: Grid<char> = "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO".parse().unwrap();
+        let regions = grid.regions();
+        assert_eq!(regions.regions.len(), 5);
+        let o = &regions.regions[0];
+        assert_eq!((o.area(), o.perimeter, o.sides), (21, 36, 20));
+        assert_eq!(o.bounds, ((0, 0), (4, 4)));
+    }
+
+    #[test]
+    fn regions_where_skips_walls() {
+        let grid: Grid<char> = "..#..\n..#..\n#####\n.....".parse().unwrap();
+        let regions = grid.regions_where(|&c| c == '.');
+        assert_eq!(regions.regions.len(), 3);
+        assert_eq!(regions.labels[(0, 2)], None);
+        assert_eq!(regions.region_of((3, 4)).unwrap().area(), 5);
+        assert_eq!(grid.flood_fill((0, 0), |a, b| a == b).len(), 4);
+    }
+
+    #[test]
+    fn large_region_does_not_overflow() {
+        let grid = Grid::filled(400, 400, 0u8);
+        let regions = grid.regions();
+        assert_eq!(regions.regions[0].area(), 160_000);
+        assert_eq!(regions.regions[0].sides, 4);
+    }
+}
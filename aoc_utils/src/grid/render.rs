@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use super::{Grid, Point};
+
+/// ANSI foreground colours for highlighted cells
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi(self) -> &'static str {
+        match self {
+            Color::Red => "\x1b[31m",
+            Color::Green => "\x1b[32m",
+            Color::Yellow => "\x1b[33m",
+            Color::Blue => "\x1b[34m",
+            Color::Magenta => "\x1b[35m",
+            Color::Cyan => "\x1b[36m",
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// Draws a grid as text into any `io::Write`
+///
+/// Example usage:
+/// ```
+/// use aoc_utils::grid::{Color, Grid, Renderer};
+///
+/// let grid: Grid<char> = "#..\n.#.".parse().unwrap();
+/// let mut out = Vec::new();
+/// Renderer::new(&grid, |&c| c)
+///     .highlight([(0, 1), (0, 2)], Color::Red)
+///     .rulers(true)
+///     .write_to(&mut out)
+///     .unwrap();
+/// ```
+pub struct Renderer<'a, T> {
+    grid: &'a Grid<T>,
+    cell: Box<dyn Fn(&T) -> char + 'a>,
+    highlights: HashMap<Point, Color>,
+    rulers: bool,
+}
+
+impl<'a, T> Renderer<'a, T> {
+    /// `cell` turns every cell into the character that is drawn for it
+    pub fn new(grid: &'a Grid<T>, cell: impl Fn(&T) -> char + 'a) -> Self {
+        Renderer {
+            grid,
+            cell: Box::new(cell),
+            highlights: HashMap::new(),
+            rulers: false,
+        }
+    }
+
+    /// Colours the given points, later calls win when points overlap
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, color: Color) -> Self {
+        self.highlights
+            .extend(points.into_iter().map(|p| (p, color)));
+        self
+    }
+
+    /// Prefixes every row with its index and adds column indexes on top
+    pub fn rulers(mut self, rulers: bool) -> Self {
+        self.rulers = rulers;
+        self
+    }
+
+    pub fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        let margin = self.grid.height().saturating_sub(1).to_string().len();
+        if self.rulers {
+            // one line per digit of the widest column index, most significant first
+            let digits = self.grid.width().saturating_sub(1).to_string().len();
+            for d in (0..digits).rev() {
+                write!(out, "{:margin$} ", "")?;
+                for c in 0..self.grid.width() {
+                    let div = 10usize.pow(d as u32);
+                    if c >= div || d == 0 {
+                        write!(out, "{}", c / div % 10)?;
+                    } else {
+                        write!(out, " ")?;
+                    }
+                }
+                writeln!(out)?;
+            }
+        }
+        for (r, row) in self.grid.rows().enumerate() {
+            if self.rulers {
+                write!(out, "{:>margin$} ", r)?;
+            }
+            for (c, cell) in row.iter().enumerate() {
+                let ch = (self.cell)(cell);
+                match self.highlights.get(&(r, c)) {
+                    Some(color) => write!(out, "{}{}{}", color.ansi(), ch, RESET)?,
+                    None => write!(out, "{}", ch)?,
+                }
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+
+    /// Writes to stdout
+    pub fn print(&self) -> io::Result<()> {
+        self.write_to(&mut io::stdout().lock())
+    }
+
+    pub fn render_string(&self) -> String {
+        let mut out = Vec::new();
+        self.write_to(&mut out)
+            .expect("writing to a Vec can't fail");
+        String::from_utf8(out).expect("rendered grid is valid utf-8")
+    }
+}
+
+/// Writes a plain (P1) PBM image, one pixel per cell, black where `on` holds.
+/// Use `Grid::scale` first to get bigger pixels.
+pub fn write_pbm<T>(
+    grid: &Grid<T>,
+    out: &mut impl Write,
+    on: impl Fn(&T) -> bool,
+) -> io::Result<()> {
+    writeln!(out, "P1")?;
+    writeln!(out, "{} {}", grid.width(), grid.height())?;
+    for row in grid.rows() {
+        let pixels = row.iter().map(|cell| if on(cell) { "1" } else { "0" });
+        write_plain_row(out, pixels, " ")?;
+    }
+    Ok(())
+}
+
+/// Writes a plain (P3) PPM image, one pixel per cell coloured by `color` as [r, g, b]
+pub fn write_ppm<T>(
+    grid: &Grid<T>,
+    out: &mut impl Write,
+    color: impl Fn(&T) -> [u8; 3],
+) -> io::Result<()> {
+    writeln!(out, "P3")?;
+    writeln!(out, "{} {}", grid.width(), grid.height())?;
+    writeln!(out, "255")?;
+    for row in grid.rows() {
+        let pixels = row.iter().map(|cell| {
+            let [r, g, b] = color(cell);
+            format!("{} {} {}", r, g, b)
+        });
+        write_plain_row(out, pixels, "  ")?;
+    }
+    Ok(())
+}
+
+/// Plain PBM and PPM lines can't be longer than 70 characters, long rows go on several lines
+fn write_plain_row<S: AsRef<str>>(
+    out: &mut impl Write,
+    pixels: impl Iterator<Item = S>,
+    separator: &str,
+) -> io::Result<()> {
+    const MAX_LINE: usize = 70;
+    let mut line = String::new();
+    for pixel in pixels {
+        let pixel = pixel.as_ref();
+        if !line.is_empty() {
+            if line.len() + separator.len() + pixel.len() > MAX_LINE {
+                writeln!(out, "{}", line)?;
+                line.clear();
+            } else {
+                line += separator;
+            }
+        }
+        line += pixel;
+    }
+    writeln!(out, "{}", line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_render() {
+        let grid: Grid<u8> = Grid::new(3, 2, vec![0, 1, 0, 2, 0, 0]);
+        let s = Renderer::new(&grid, |&n| if n == 0 { '.' } else { '#' }).render_string();
+        assert_eq!(s, ".#.\n#..\n");
+    }
+
+    #[test]
+    fn highlights_and_rulers() {
+        let grid: Grid<char> = Grid::filled(12, 2, '.');
+        let s = Renderer::new(&grid, |&c| c)
+            .highlight([(1, 11)], Color::Green)
+            .rulers(true)
+            .render_string();
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(lines[0], "            11");
+        assert_eq!(lines[1], "  012345678901");
+        assert_eq!(lines[2], "0 ............");
+        assert_eq!(lines[3], "1 ...........\x1b[32m.\x1b[0m");
+    }
+
+    #[test]
+    fn images() {
+        let grid: Grid<char> = "#.\n.#".parse().unwrap();
+        let mut pbm = Vec::new();
+        write_pbm(&grid, &mut pbm, |&c| c == '#').unwrap();
+        assert_eq!(String::from_utf8(pbm).unwrap(), "P1\n2 2\n1 0\n0 1\n");
+        let mut ppm = Vec::new();
+        write_ppm(&grid, &mut ppm, |&c| {
+            if c == '#' {
+                [255, 0, 0]
+            } else {
+                [0, 0, 0]
+            }
+        })
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(ppm).unwrap(),
+            "P3\n2 2\n255\n255 0 0  0 0 0\n0 0 0  255 0 0\n"
+        );
+    }
+
+    #[test]
+    fn long_rows_are_wrapped() {
+        let grid: Grid<bool> = Grid::filled(40, 1, true);
+        let mut pbm = Vec::new();
+        write_pbm(&grid, &mut pbm, |&on| on).unwrap();
+        let pbm = String::from_utf8(pbm).unwrap();
+        let lines: Vec<&str> = pbm.lines().skip(2).collect();
+        assert_eq!(lines, [["1"; 35].join(" "), ["1"; 5].join(" ")]);
+
+        let mut ppm = Vec::new();
+        write_ppm(&grid, &mut ppm, |_| [255, 255, 255]).unwrap();
+        let ppm = String::from_utf8(ppm).unwrap();
+        assert!(ppm.lines().all(|line| line.len() <= 70));
+        assert_eq!(ppm.matches("255 255 255").count(), 40);
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use super::{Direction, Grid, Vec2};
+
+/// Unbounded grid that only stores occupied cells, for coordinates that are huge, negative
+/// or not known in advance. Keeps track of the bounding box of everything inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2, T>,
+    /// Inclusive (min, max) corners, `None` while empty
+    bounds: Option<(Vec2, Vec2)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copies the cells of a dense grid for which `keep` holds, placing (0, 0) at `offset`
+    pub fn from_dense(grid: &Grid<T>, offset: Vec2, keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        grid.iter()
+            .filter(|(_, cell)| keep(cell))
+            .map(|(p, cell)| (Vec2::from(p) + offset, cell.clone()))
+            .collect()
+    }
+
+    /// Dense copy of the bounding box with missing cells set to `empty`, together with the
+    /// offset to add to a dense `Point` to get back the sparse position
+    pub fn to_dense(&self, empty: T) -> (Grid<T>, Vec2)
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return (Grid::new(0, 0, Vec::new()), Vec2::ZERO);
+        };
+        let size = max - min;
+        let mut grid = Grid::filled(size.c as usize + 1, size.r as usize + 1, empty);
+        for (&p, cell) in &self.cells {
+            grid[(p - min).to_point().unwrap()] = cell.clone();
+        }
+        (grid, min)
+    }
+
+    /// Inserts a cell and grows the bounding box, returns the previous value
+    pub fn insert(&mut self, p: Vec2, value: T) -> Option<T> {
+        self.bounds = Some(grow(self.bounds, p));
+        self.cells.insert(p, value)
+    }
+
+    /// Removes a cell, the bounding box shrinks if it was on its edge
+    pub fn remove(&mut self, p: Vec2) -> Option<T> {
+        let removed = self.cells.remove(&p)?;
+        if let Some((min, max)) = self.bounds {
+            if p.r == min.r || p.r == max.r || p.c == min.c || p.c == max.c {
+                self.recompute_bounds();
+            }
+        }
+        Some(removed)
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = self.cells.keys().fold(None, |b, &p| Some(grow(b, p)));
+    }
+
+    /// Inclusive (min, max) corners of all occupied cells
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        self.bounds
+    }
+
+    pub fn get(&self, p: Vec2) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Vec2) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    pub fn contains(&self, p: Vec2) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Occupied cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> + '_ {
+        self.cells.iter().map(|(&p, cell)| (p, cell))
+    }
+
+    /// Occupied neighbours of `p` in the given directions
+    pub fn neighbors_with<'a>(
+        &'a self,
+        p: Vec2,
+        dirs: &'a [Direction],
+    ) -> impl Iterator<Item = (Direction, Vec2, &'a T)> + 'a {
+        dirs.iter().filter_map(move |&dir| {
+            let n = p + dir.resolve();
+            self.cells.get(&n).map(|cell| (dir, n, cell))
+        })
+    }
+
+    /// Occupied neighbours of `p` going up, down, left and right
+    pub fn neighbors4(&self, p: Vec2) -> impl Iterator<Item = (Direction, Vec2, &T)> + '_ {
+        self.neighbors_with(p, &super::STRAIGHT)
+    }
+
+    /// Occupied neighbours of `p` including the diagonals
+    pub fn neighbors8(&self, p: Vec2) -> impl Iterator<Item = (Direction, Vec2, &T)> + '_ {
+        self.neighbors_with(p, &super::ALL)
+    }
+}
+
+/// Smallest box containing `bounds` and `p`
+fn grow(bounds: Option<(Vec2, Vec2)>, p: Vec2) -> (Vec2, Vec2) {
+    match bounds {
+        None => (p, p),
+        Some((min, max)) => (
+            Vec2::new(min.r.min(p.r), min.c.min(p.c)),
+            Vec2::new(max.r.max(p.r), max.c.max(p.c)),
+        ),
+    }
+}
+
+impl<T> FromIterator<(Vec2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (p, cell) in iter {
+            grid.insert(p, cell);
+        }
+        grid
+    }
+}
+
+/// Draws the bounding box row by row, missing cells as `.`
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for r in min.r..=max.r {
+            for c in min.c..=max.c {
+                match self.cells.get(&Vec2::new(r, c)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_follow_inserts_and_removes() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Vec2::new(-5, 2), '#');
+        grid.insert(Vec2::new(3, -7), '#');
+        grid.insert(Vec2::new(0, 0), 'S');
+        assert_eq!(grid.bounds(), Some((Vec2::new(-5, -7), Vec2::new(3, 2))));
+        assert_eq!(grid.remove(Vec2::new(3, -7)), Some('#'));
+        assert_eq!(grid.bounds(), Some((Vec2::new(-5, 0), Vec2::new(0, 2))));
+        assert_eq!(grid.remove(Vec2::new(3, -7)), None);
+        assert_eq!(grid.len(), 2);
+    }
+
+    #[test]
+    fn neighbours_are_occupied_cells() {
+        let grid: SparseGrid<u8> = [
+            (Vec2::new(0, 0), 1),
+            (Vec2::new(-1, 0), 2),
+            (Vec2::new(1, 1), 3),
+        ]
+        .into_iter()
+        .collect();
+        let n4: Vec<_> = grid.neighbors4(Vec2::ZERO).map(|(_, _, &v)| v).collect();
+        assert_eq!(n4, vec![2]);
+        assert_eq!(grid.neighbors8(Vec2::ZERO).count(), 2);
+    }
+
+    #[test]
+    fn dense_round_trip() {
+        let dense: Grid<char> = "..#\n#..".parse().unwrap();
+        let sparse = SparseGrid::from_dense(&dense, Vec2::new(-1, -1), |&c| c == '#');
+        assert_eq!(sparse.len(), 2);
+        assert!(sparse.contains(Vec2::new(-1, 1)));
+        assert!(sparse.contains(Vec2::new(0, -1)));
+        assert_eq!(sparse.to_string(), "..#\n#..\n");
+        let (back, offset) = sparse.to_dense('.');
+        assert_eq!(back, dense);
+        assert_eq!(offset, Vec2::new(-1, -1));
+    }
+}
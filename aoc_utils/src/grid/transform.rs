@@ -0,0 +1,200 @@
+use std::ops::Index;
+
+use super::{Grid, Point};
+
+impl<T: Clone> Grid<T> {
+    /// Builds a `height` x `width` grid where every cell is taken from `self` at `source(r, c)`
+    fn remap(&self, height: usize, width: usize, source: impl Fn(Point) -> Point) -> Grid<T> {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for r in 0..height {
+            for c in 0..width {
+                cells.push(self[source((r, c))].clone());
+            }
+        }
+        Grid::new(width, height, cells)
+    }
+
+    /// Rows become columns, (r, c) -> (c, r)
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.width, self.height, |(r, c)| (c, r))
+    }
+
+    /// 90° clockwise, the first row becomes the last column
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let h = self.height;
+        self.remap(self.width, self.height, |(r, c)| (h - 1 - c, r))
+    }
+
+    /// 90° counter clockwise, the first row becomes the first column upside down
+    pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+        let w = self.width;
+        self.remap(self.width, self.height, |(r, c)| (c, w - 1 - r))
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let mut cells = self.cells.clone();
+        cells.reverse();
+        Grid::new(self.width, self.height, cells)
+    }
+
+    /// Mirrors left to right, every row is reversed
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let mut grid = self.clone();
+        for r in 0..grid.height {
+            grid.row_mut(r).reverse();
+        }
+        grid
+    }
+
+    /// Mirrors top to bottom, the order of the rows is reversed
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let cells = self.rows().rev().flatten().cloned().collect();
+        Grid::new(self.width, self.height, cells)
+    }
+
+    /// Every cell becomes a `factor` x `factor` block of copies
+    pub fn scale(&self, factor: usize) -> Grid<T> {
+        self.remap(self.height * factor, self.width * factor, |(r, c)| {
+            (r / factor, c / factor)
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Widens every cell into `K` cells placed left to right, e.g. `#` -> `##`
+    pub fn scale_cells<U, const K: usize>(&self, f: impl Fn(&T) -> [U; K]) -> Grid<U> {
+        let cells = self.cells.iter().flat_map(f).collect();
+        Grid::new(self.width * K, self.height, cells)
+    }
+
+    /// Borrowed `height` x `width` window with its top-left corner at `top_left`,
+    /// `None` if it doesn't fit inside the grid
+    pub fn view(&self, top_left: Point, height: usize, width: usize) -> Option<GridView<'_, T>> {
+        let (r, c) = top_left;
+        if r + height > self.height || c + width > self.width {
+            return None;
+        }
+        Some(GridView {
+            grid: self,
+            top_left,
+            width,
+            height,
+        })
+    }
+}
+
+/// Rectangular window into a `Grid` that doesn't copy any cells
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    top_left: Point,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Position of the view's top-left cell in the underlying grid
+    pub fn top_left(&self) -> Point {
+        self.top_left
+    }
+
+    /// Cell at (r, c) relative to the view
+    pub fn get(&self, (r, c): Point) -> Option<&'a T> {
+        if r < self.height && c < self.width {
+            self.grid.get((self.top_left.0 + r, self.top_left.1 + c))
+        } else {
+            None
+        }
+    }
+
+    /// Row `r` of the view, a slice of the underlying row
+    pub fn row(&self, r: usize) -> &'a [T] {
+        assert!(r < self.height, "Row {} out of range {}", r, self.height);
+        let c = self.top_left.1;
+        &self.grid.row(self.top_left.0 + r)[c..c + self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(|r| self.row(r))
+    }
+
+    /// Cells in row-major order with positions relative to the view
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        self.rows()
+            .enumerate()
+            .flat_map(|(r, row)| row.iter().enumerate().map(move |(c, cell)| ((r, c), cell)))
+    }
+
+    /// Copies the window into its own grid
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.rows().flatten().cloned().collect();
+        Grid::new(self.width, self.height, cells)
+    }
+}
+
+impl<T> Index<Point> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("Point {:?} out of range {}x{}", p, self.height, self.width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn rotations_and_mirrors() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose(), grid("ad\nbe\ncf"));
+        assert_eq!(g.rotate_clockwise(), grid("da\neb\nfc"));
+        assert_eq!(g.rotate_counter_clockwise(), grid("cf\nbe\nad"));
+        assert_eq!(g.rotate_180(), grid("fed\ncba"));
+        assert_eq!(g.flip_horizontal(), grid("cba\nfed"));
+        assert_eq!(g.flip_vertical(), grid("def\nabc"));
+        assert_eq!(g.rotate_clockwise().rotate_clockwise(), g.rotate_180());
+        assert_eq!(g.rotate_clockwise().rotate_counter_clockwise(), g);
+    }
+
+    #[test]
+    fn scaling() {
+        let g = grid("#O\n.@");
+        assert_eq!(g.scale(2), grid("##OO\n##OO\n..@@\n..@@"));
+        let wide = g.scale_cells(|&c| match c {
+            'O' => ['[', ']'],
+            '@' => ['@', '.'],
+            c => [c, c],
+        });
+        assert_eq!(wide, grid("##[]\n..@."));
+    }
+
+    #[test]
+    fn views() {
+        let g = grid("abcd\nefgh\nijkl");
+        let v = g.view((1, 1), 2, 3).unwrap();
+        assert_eq!(v.row(0), &['f', 'g', 'h']);
+        assert_eq!(v[(1, 0)], 'j');
+        assert_eq!(v.get((0, 3)), None);
+        assert_eq!(v.to_grid(), grid("fgh\njkl"));
+        assert_eq!(v.iter().count(), 6);
+        assert!(g.view((1, 1), 3, 1).is_none());
+        assert!(g.view((0, 2), 1, 3).is_none());
+    }
+}
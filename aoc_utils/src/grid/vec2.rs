@@ -0,0 +1,159 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::Point;
+
+/// Signed (r, c) vector, used both for positions that can leave the grid and for offsets
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub r: isize,
+    pub c: isize,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { r: 0, c: 0 };
+
+    pub const fn new(r: isize, c: isize) -> Self {
+        Vec2 { r, c }
+    }
+
+    /// |r| + |c|
+    pub fn manhattan(self) -> usize {
+        self.r.unsigned_abs() + self.c.unsigned_abs()
+    }
+
+    /// max(|r|, |c|), the number of king moves to reach the vector
+    pub fn chebyshev(self) -> usize {
+        self.r.unsigned_abs().max(self.c.unsigned_abs())
+    }
+
+    pub fn manhattan_to(self, other: Vec2) -> usize {
+        (other - self).manhattan()
+    }
+
+    pub fn chebyshev_to(self, other: Vec2) -> usize {
+        (other - self).chebyshev()
+    }
+
+    /// Converts to a `Point`, `None` if any coordinate is negative
+    pub fn to_point(self) -> Option<Point> {
+        Some((usize::try_from(self.r).ok()?, usize::try_from(self.c).ok()?))
+    }
+
+    /// Converts to a `Point` that is inside a `height` x `width` area
+    pub fn to_point_within(self, height: usize, width: usize) -> Option<Point> {
+        self.to_point().filter(|&(r, c)| r < height && c < width)
+    }
+
+    /// Wraps both coordinates into `height` x `width`, negative values wrap from the end
+    pub fn wrap(self, height: usize, width: usize) -> Point {
+        (
+            self.r.rem_euclid(height as isize) as usize,
+            self.c.rem_euclid(width as isize) as usize,
+        )
+    }
+}
+
+impl From<Point> for Vec2 {
+    /// Panics if the point doesn't fit in `isize`
+    fn from((r, c): Point) -> Self {
+        Vec2 {
+            r: isize::try_from(r).expect("row doesn't fit in isize"),
+            c: isize::try_from(c).expect("column doesn't fit in isize"),
+        }
+    }
+}
+
+impl From<(isize, isize)> for Vec2 {
+    fn from((r, c): (isize, isize)) -> Self {
+        Vec2 { r, c }
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.r, self.c)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.r + rhs.r, self.c + rhs.c)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.r - rhs.r, self.c - rhs.c)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: isize) -> Vec2 {
+        Vec2::new(self.r * rhs, self.c * rhs)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.r, -self.c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(-3, 5);
+        assert_eq!(a + b, Vec2::new(-2, 3));
+        assert_eq!(a - b, Vec2::new(4, -7));
+        assert_eq!(b * 3, Vec2::new(-9, 15));
+        assert_eq!(-a, Vec2::new(-1, 2));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(-3, 5);
+        assert_eq!(a.manhattan_to(b), 11);
+        assert_eq!(a.chebyshev_to(b), 7);
+        assert_eq!(Vec2::ZERO.manhattan(), 0);
+    }
+
+    #[test]
+    fn point_conversion() {
+        assert_eq!(Vec2::from((3usize, 4usize)), Vec2::new(3, 4));
+        assert_eq!(Vec2::new(3, 4).to_point(), Some((3, 4)));
+        assert_eq!(Vec2::new(-1, 4).to_point(), None);
+        assert_eq!(Vec2::new(3, 4).to_point_within(4, 5), Some((3, 4)));
+        assert_eq!(Vec2::new(3, 4).to_point_within(3, 5), None);
+        assert_eq!(Vec2::new(-1, 12).wrap(7, 11), (6, 1));
+    }
+}
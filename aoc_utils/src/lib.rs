@@ -1,2 +1,12 @@
+pub mod cli;
+#[cfg(feature = "fetch")]
+pub mod fetch;
 pub mod grid;
+#[cfg(feature = "nom")]
+pub mod nom;
+pub mod parse;
+pub mod puzzle;
 pub mod reader;
+pub mod recorder;
+pub mod search;
+pub mod solution;
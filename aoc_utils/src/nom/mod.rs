@@ -0,0 +1,220 @@
+//! Reusable nom parsers for the usual puzzle input shapes.
+//! Only built with the `nom` feature.
+use std::fmt;
+use std::str::FromStr;
+
+use ::nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::{char, digit1, line_ending, multispace0, one_of, space0},
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    Finish, IResult, Parser,
+};
+
+use crate::grid::Grid;
+
+/// Unsigned integer of any width, `"42"`
+pub fn uint<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Integer with an optional `-` or `+` sign, `"-42"`
+pub fn int<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// One or more `item`s separated by `sep`. Spaces around the separator are skipped unless
+/// the separator itself is whitespace like `" "` or `"\n"`.
+pub fn list<'a, O>(
+    sep: &'a str,
+    item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    let trimmed = sep.trim();
+    let separator = move |input| {
+        if trimmed.is_empty() {
+            tag(sep)(input)
+        } else {
+            delimited(space0, tag(trimmed), space0)(input)
+        }
+    };
+    separated_list1(separator, item)
+}
+
+/// `key=value`, the key is any run of letters, digits or `_`
+pub fn key_value<'a, O>(
+    value: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, O)> {
+    separated_pair(
+        take_while1(|c: char| c.is_alphanumeric() || c == '_'),
+        char('='),
+        value,
+    )
+}
+
+/// `x,y` as two signed integers
+pub fn coord2<T: FromStr>(input: &str) -> IResult<&str, (T, T)> {
+    separated_pair(int, pair(char(','), space0), int)(input)
+}
+
+/// `x,y,z` as three signed integers
+pub fn coord3<T: FromStr>(input: &str) -> IResult<&str, (T, T, T)> {
+    let sep = || pair(char(','), space0);
+    tuple((int, preceded(sep(), int), preceded(sep(), int)))(input)
+}
+
+/// `inner` between an `open` and `close` character
+pub fn enclosed<'a, O>(
+    open: char,
+    inner: impl Parser<&'a str, O, Error<&'a str>>,
+    close: char,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    delimited(char(open), inner, char(close))
+}
+
+/// `[inner]`
+pub fn bracketed<'a, O>(
+    inner: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    enclosed('[', inner, ']')
+}
+
+/// `(inner)`
+pub fn parenthesized<'a, O>(
+    inner: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    enclosed('(', inner, ')')
+}
+
+/// `{inner}`
+pub fn braced<'a, O>(
+    inner: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    enclosed('{', inner, '}')
+}
+
+/// Block of non-empty lines as a grid, stops at a blank line or the end of input
+pub fn grid<T: TryFrom<char>>(input: &str) -> IResult<&str, Grid<T>> {
+    map_res(
+        separated_list1(line_ending, take_while1(|c| c != '\n' && c != '\r')),
+        Grid::from_lines,
+    )(input)
+}
+
+/// Sections separated by blank lines, each parsed with `section`
+pub fn sections<'a, O>(
+    section: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(line_ending, line_ending), section)
+}
+
+/// Runs `parser` on the whole input, trailing whitespace is allowed but anything else
+/// left over is an error
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, O, Error<&'a str>>,
+) -> Result<O, NomError> {
+    all_consuming(terminated(parser, multispace0))(input)
+        .finish()
+        .map(|(_, out)| out)
+        .map_err(|e| NomError::new(input, e))
+}
+
+/// nom error located in the original input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NomError {
+    /// Counts from 1
+    pub line: usize,
+    /// Counts from 1, in characters
+    pub column: usize,
+    /// The whole line the error is on
+    pub text: String,
+    pub kind: ErrorKind,
+}
+
+impl NomError {
+    /// Locates `err` in `input`, the error has to point into `input`
+    pub fn new(input: &str, err: Error<&str>) -> Self {
+        let offset = input.len() - err.input.len();
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let text = input[line_start..].lines().next().unwrap_or("");
+        NomError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            kind: err.code,
+        }
+    }
+}
+
+impl fmt::Display for NomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: couldn't parse ({:?})",
+            self.line, self.column, self.kind
+        )?;
+        writeln!(f, "{}", self.text)?;
+        write!(f, "{:>1$}", "^", self.column)
+    }
+}
+
+impl std::error::Error for NomError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::nom::character::complete::space1;
+
+    #[test]
+    fn numbers_and_lists() {
+        assert_eq!(uint::<u8>("42,"), Ok((",", 42)));
+        assert!(uint::<u8>("-1").is_err());
+        assert!(uint::<u8>("300").is_err());
+        assert_eq!(int::<i64>("-17 x"), Ok((" x", -17)));
+        assert_eq!(list(",", int::<i32>)("1, -2,3"), Ok(("", vec![1, -2, 3])));
+        assert_eq!(key_value(int::<i32>)("x_1=-5"), Ok(("", ("x_1", -5))));
+        assert_eq!(coord2::<i32>("3,-4"), Ok(("", (3, -4))));
+        assert_eq!(coord3::<u32>("1, 2, 3"), Ok(("", (1, 2, 3))));
+    }
+
+    #[test]
+    fn groups() {
+        let lights = bracketed(take_while1(|c| c == '.' || c == '#'));
+        let buttons = list(" ", parenthesized(list(",", uint::<usize>)));
+        let joltage = braced(list(",", uint::<usize>));
+        let (lights, buttons, joltage) = parse_all(
+            "[.##.] (3) (1,3) {3,5}\n",
+            tuple((lights, preceded(space1, buttons), preceded(space1, joltage))),
+        )
+        .unwrap();
+        assert_eq!(lights, ".##.");
+        assert_eq!(buttons, vec![vec![3], vec![1, 3]]);
+        assert_eq!(joltage, vec![3, 5]);
+    }
+
+    #[test]
+    fn grids_and_sections() {
+        let (rest, grids) = sections(grid::<char>)("#.\n.#\n\nab\ncd\nef\n").unwrap();
+        assert_eq!(rest, "\n");
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[1].height(), 3);
+        let (_, lists) = sections(list("\n", uint::<u32>))("1\n2\n\n3").unwrap();
+        assert_eq!(lists, vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn errors_point_at_the_column() {
+        let err = parse_all("[1,x]", bracketed(list(",", uint::<u8>))).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: couldn't parse (Char)\n[1,x]\n  ^"
+        );
+        // a failed item is backtracked, the leftover input is reported instead
+        let err = parse_all("1,2\n3,x", list("\n", coord2::<i32>)).unwrap_err();
+        assert_eq!((err.line, err.column, err.kind), (2, 1, ErrorKind::Eof));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::grid::Grid;
+
+/// Column-aligned piece of a worksheet, whitespace inside it is kept as is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    /// Column of the original text where the block starts
+    pub start: usize,
+    pub cells: Grid<char>,
+}
+
+impl Block {
+    /// Lines of the block top to bottom, all padded to the block's width
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = String> + '_ {
+        self.cells.rows().map(|row| row.iter().collect())
+    }
+
+    /// Columns of the block left to right, each read top to bottom
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = String> + '_ {
+        (0..self.cells.width()).map(|c| self.cells.column(c).collect())
+    }
+}
+
+/// Splits `text` on columns that are blank in every line, e.g.
+/// ```text
+/// 123 328
+///  45 64
+///   6 98
+/// *   +
+/// ```
+/// gives the blocks `123`/` 45`/`  6`/`*  ` and `328`/`64 `/`98 `/`+  `.
+/// Lines shorter than the longest one are padded with spaces first.
+///
+/// Example usage:
+/// ```
+/// use aoc_utils::parse::column_blocks;
+///
+/// let blocks = column_blocks("123 328\n 45 64\n  6 98\n*   +");
+/// assert_eq!(blocks.len(), 2);
+/// let columns: Vec<String> = blocks[0].columns().collect();
+/// assert_eq!(columns, vec!["1  *", "24  ", "356 "]);
+/// ```
+pub fn column_blocks(text: &str) -> Vec<Block> {
+    let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+    let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+    let rows = lines
+        .into_iter()
+        .map(|mut line| {
+            line.resize(width, ' ');
+            line
+        })
+        .collect();
+    let sheet = Grid::from_rows(rows).expect("every line is padded to the same width");
+    let blank: Vec<bool> = (0..width)
+        .map(|c| sheet.column(c).all(|ch| ch.is_whitespace()))
+        .collect();
+
+    let mut blocks = Vec::new();
+    let mut c = 0;
+    while c < width {
+        if blank[c] {
+            c += 1;
+            continue;
+        }
+        let start = c;
+        while c < width && !blank[c] {
+            c += 1;
+        }
+        let view = sheet
+            .view((0, start), sheet.height(), c - start)
+            .expect("block lies inside the sheet");
+        blocks.push(Block {
+            start,
+            cells: view.to_grid(),
+        });
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
+
+    #[test]
+    fn splits_on_blank_columns() {
+        let blocks = column_blocks(SHEET);
+        let starts: Vec<usize> = blocks.iter().map(|b| b.start).collect();
+        assert_eq!(starts, vec![0, 4, 8, 12]);
+        let rows: Vec<String> = blocks[1].rows().collect();
+        assert_eq!(rows, vec!["328", "64 ", "98 ", "+  "]);
+        let columns: Vec<String> = blocks[3].columns().rev().collect();
+        assert_eq!(columns, vec!["  4 ", "431 ", "623+"]);
+    }
+
+    #[test]
+    fn short_lines_are_padded() {
+        let blocks = column_blocks("ab  c\na\n   dd");
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].cells.height(), 3);
+        let rows: Vec<String> = blocks[1].rows().collect();
+        assert_eq!(rows, vec![" c", "  ", "dd"]);
+        assert!(column_blocks("").is_empty());
+    }
+}
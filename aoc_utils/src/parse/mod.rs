@@ -0,0 +1,151 @@
+use std::error::Error;
+use std::fmt;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+mod blocks;
+
+pub use blocks::{column_blocks, Block};
+
+/// Every integer in `line`, ignoring whatever text surrounds them.
+/// A `-` directly in front of digits is a sign unless it follows another digit, so
+/// `p=0,4 v=3,-3` gives `[0, 4, 3, -3]` while `1-3` gives `[1, 3]`.
+/// For unsigned types the `-` is always treated as a separator.
+///
+/// Example usage:
+/// ```
+/// use aoc_utils::parse::ints;
+///
+/// let numbers: Vec<i64> = ints("Register A: 729, B: -2").unwrap();
+/// assert_eq!(numbers, vec![729, -2]);
+/// ```
+pub fn ints<T>(line: &str) -> Result<Vec<T>, IntsError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    tokens(line).map(parse_int).collect()
+}
+
+/// Exactly `N` integers from `line`, see `ints` for what counts as one
+///
+/// Example usage:
+/// ```
+/// use aoc_utils::parse::ints_n;
+///
+/// let [x, y, dx, dy] = ints_n::<i32, 4>("p=0,4 v=3,-3").unwrap();
+/// assert_eq!((x, y, dx, dy), (0, 4, 3, -3));
+/// ```
+pub fn ints_n<T, const N: usize>(line: &str) -> Result<[T; N], IntsError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let numbers = ints(line)?;
+    let found = numbers.len();
+    numbers
+        .try_into()
+        .map_err(|_| IntsError::Count { expected: N, found })
+}
+
+/// Splits out every run of digits, including a leading sign
+fn tokens(line: &str) -> impl Iterator<Item = &str> {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let signed = bytes[i] == b'-'
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && (i == 0 || !bytes[i - 1].is_ascii_digit());
+            if signed || bytes[i].is_ascii_digit() {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                return Some(&line[start..i]);
+            }
+            i += 1;
+        }
+        None
+    })
+}
+
+fn parse_int<T: FromStr<Err = ParseIntError>>(token: &str) -> Result<T, IntsError> {
+    match token.parse::<T>() {
+        // only unsigned types reject a sign as an invalid digit
+        Err(e) if token.starts_with('-') && *e.kind() == IntErrorKind::InvalidDigit => {
+            parse_int(&token[1..])
+        }
+        res => res.map_err(|source| IntsError::Int {
+            text: token.to_string(),
+            source,
+        }),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntsError {
+    /// The number doesn't fit the requested type
+    Int {
+        text: String,
+        source: ParseIntError,
+    },
+    Count {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for IntsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntsError::Int { text, source } => write!(f, "couldn't parse '{}': {}", text, source),
+            IntsError::Count { expected, found } => {
+                write!(f, "expected {} integers, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for IntsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IntsError::Int { source, .. } => Some(source),
+            IntsError::Count { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signs() {
+        assert_eq!(ints::<i32>("p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(ints::<i32>("1-3 a: -7--2"), Ok(vec![1, 3, -7, -2]));
+        assert_eq!(ints::<u32>("x=-5, y=12"), Ok(vec![5, 12]));
+        assert_eq!(ints::<i64>("no numbers - here"), Ok(vec![]));
+        assert_eq!(
+            ints::<u128>("340282366920938463463374607431768211455"),
+            Ok(vec![u128::MAX])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = ints::<u8>("1, 256").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "couldn't parse '256': number too large to fit in target type"
+        );
+        assert!(matches!(ints::<i8>("-129"), Err(IntsError::Int { .. })));
+        assert_eq!(
+            ints_n::<i32, 3>("1,2"),
+            Err(IntsError::Count {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(ints_n::<usize, 2>("Button A: X+94, Y+34"), Ok([94, 34]));
+    }
+}
@@ -0,0 +1,199 @@
+//! Pulls example inputs and their answers out of a saved puzzle page.
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// What could be read from a puzzle page
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzlePage {
+    /// Every `<pre><code>` block in page order, as plain text
+    pub examples: Vec<String>,
+    /// Answer to the example of each part that is on the page, part 1 first
+    pub answers: Vec<String>,
+}
+
+impl PuzzlePage {
+    /// Each part is its own `<article class="day-desc">`, its example answer is the last
+    /// emphasised code (`<code><em>..</em></code>`) in it
+    pub fn parse(html: &str) -> Self {
+        let mut articles = between(html, "<article class=\"day-desc\">", "</article>");
+        if articles.is_empty() {
+            articles.push(html);
+        }
+        PuzzlePage {
+            examples: between(html, "<pre><code>", "</code></pre>")
+                .into_iter()
+                .map(to_text)
+                .collect(),
+            answers: articles
+                .into_iter()
+                .filter_map(|article| between(article, "<code><em>", "</em></code>").pop())
+                .map(to_text)
+                .collect(),
+        }
+    }
+
+    /// Writes examples `picked` (0-based) as `dayN.test`, `dayN.test2`, ... into `dir`,
+    /// the numbering the solutions already use for several examples
+    pub fn write_examples(
+        &self,
+        dir: impl AsRef<Path>,
+        day: u8,
+        picked: &[usize],
+    ) -> io::Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir.as_ref())?;
+        let mut paths = Vec::new();
+        for (i, &example) in picked.iter().enumerate() {
+            let text = self.examples.get(example).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("the page has no example {}", example + 1),
+                )
+            })?;
+            let suffix = if i == 0 {
+                String::new()
+            } else {
+                (i + 1).to_string()
+            };
+            let path = dir.as_ref().join(format!("day{}.test{}", day, suffix));
+            fs::write(&path, text)?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
+
+/// Outcome of `patch_expected`, `replaced` falls short of the answers when the source has
+/// fewer `let expected` lines than the page has answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Patched {
+    pub source: String,
+    /// Lines that got an answer
+    pub replaced: usize,
+    /// Every `let expected = ..;` line of the source
+    pub slots: usize,
+}
+
+/// Replaces the value of the first `let expected = ..;` lines of `source` with `answers`
+/// in order, numbers stay bare and anything else becomes a string literal
+pub fn patch_expected(source: &str, answers: &[String]) -> Patched {
+    const EXPECTED: &str = "let expected = ";
+    let mut answers = answers.iter();
+    let mut out = String::with_capacity(source.len());
+    let (mut replaced, mut slots) = (0, 0);
+    for line in source.split_inclusive('\n') {
+        let patched = line.find(EXPECTED).and_then(|start| {
+            let value = start + EXPECTED.len();
+            let end = value + line[value..].find(';')?;
+            slots += 1;
+            let answer = answers.next()?;
+            let literal = if answer.parse::<i128>().is_ok() {
+                answer.clone()
+            } else {
+                format!("{:?}", answer)
+            };
+            replaced += 1;
+            Some(format!("{}{}{}", &line[..value], literal, &line[end..]))
+        });
+        out.push_str(patched.as_deref().unwrap_or(line));
+    }
+    Patched {
+        source: out,
+        replaced,
+        slots,
+    }
+}
+
+/// Every piece of `text` between `open` and the next `close`
+fn between<'a>(text: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+        let Some(end) = rest.find(close) else {
+            break;
+        };
+        found.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+    found
+}
+
+/// Drops tags like the `<em>` inside examples and decodes the escaped characters
+fn to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 12: Garden Groups ---</h2>
+<p>For example:</p>
+<pre><code>AAAA
+BBCD
+</code></pre>
+<p>Fences on the <code>A</code> side cost <code><em>40</em></code>, so in total <code><em>140</em></code>.</p>
+<pre><code>x &lt;- <em>y</em> &amp;&amp; z
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>1930</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The map above has a new total price of <code><em>80</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn examples_and_answers() {
+        let page = PuzzlePage::parse(PAGE);
+        assert_eq!(page.examples, vec!["AAAA\nBBCD\n", "x <- y && z\n"]);
+        assert_eq!(page.answers, vec!["140", "80"]);
+        assert_eq!(PuzzlePage::parse("<p>nothing</p>"), PuzzlePage::default());
+    }
+
+    #[test]
+    fn writes_numbered_examples() {
+        let dir = std::env::temp_dir().join(format!("aoc_puzzle_{}", std::process::id()));
+        let page = PuzzlePage::parse(PAGE);
+        let paths = page.write_examples(&dir, 12, &[1, 0]).unwrap();
+        assert_eq!(paths, vec![dir.join("day12.test"), dir.join("day12.test2")]);
+        assert_eq!(fs::read_to_string(&paths[1]).unwrap(), "AAAA\nBBCD\n");
+        assert!(page.write_examples(&dir, 12, &[5]).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn patches_expected_values() {
+        let source = "    fn part_1() {\n        let expected = 140;\n    }\n    fn part_2() {\n        let expected = 140;\n    }\n";
+        let patched = patch_expected(source, &["1930".to_string(), "ab,c".to_string()]);
+        assert_eq!(
+            patched.source,
+            "    fn part_1() {\n        let expected = 1930;\n    }\n    fn part_2() {\n        let expected = \"ab,c\";\n    }\n"
+        );
+        assert_eq!((patched.replaced, patched.slots), (2, 2));
+        let untouched = patch_expected(source, &[]);
+        assert_eq!(untouched.source, source);
+        assert_eq!((untouched.replaced, untouched.slots), (0, 2));
+        let short = patch_expected(source, &["1".to_string(), "2".to_string(), "3".to_string()]);
+        assert_eq!((short.replaced, short.slots), (2, 2));
+        assert_eq!(
+            patch_expected("fn main() {}\n", &["1".to_string()]).slots,
+            0
+        );
+    }
+}
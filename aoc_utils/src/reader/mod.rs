@@ -1,24 +1,344 @@
-use std::fs::File;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
 use std::io::{self, BufRead};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 /// The output is wrapped in a Result to allow matching on errors.
 /// Returns an Iterator to the Reader of the lines of the file.
+/// To parse every line into a type use `parse_lines` instead.
+pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(filename)?;
+    Ok(io::BufReader::new(file).lines())
+}
+
+/// Parses every line with `FromStr`. Stops at the first line that fails and reports its
+/// line number and text.
 ///
 /// Example usage:
-/// ```
-/// fn read_input(file_name: &str) -> Result<Input<X>> {
-///     Ok(aoc_utils::reader::read_lines(file_name)?
-///         .map_while(Result::ok)
-///         .map(|line| {
-///             line.parse()
-///                 .expect(&format!("Couldn't parse line - '{}'", line))
-///         })
-///         .collect())
+/// ```ignore
+/// fn parse(input: &str) -> Result<Self::Input> {
+///     Ok(aoc_utils::reader::parse_lines_str(input)?)
 /// }
 /// ```
-pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+pub fn parse_lines_str<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    parse_lines_with(input, str::parse)
+}
+
+/// `parse_lines_str` for types without `FromStr`, every line goes through `parse`
+pub fn parse_lines_with<T, E, F>(input: &str, parse: F) -> Result<Vec<T>, ParseError>
+where
+    E: Into<Box<dyn Error + Send + Sync>>,
+    F: Fn(&str) -> Result<T, E>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| {
+            parse(text).map_err(|e| ParseError::Line {
+                file: None,
+                line: i + 1,
+                text: text.to_string(),
+                source: e.into(),
+            })
+        })
+        .collect()
+}
+
+/// File version of `parse_lines_str`, errors also name the file
+pub fn parse_lines<T, P>(filename: P) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<Box<dyn Error + Send + Sync>>,
+    P: AsRef<Path>,
+{
+    let path = filename.as_ref().to_path_buf();
+    let text = fs::read_to_string(&path).map_err(|source| ParseError::Io {
+        file: path.clone(),
+        source,
+    })?;
+    parse_lines_str(&text).map_err(|e| match e {
+        ParseError::Line {
+            line, text, source, ..
+        } => ParseError::Line {
+            file: Some(path),
+            line,
+            text,
+            source,
+        },
+        e => e,
+    })
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    Io {
+        file: PathBuf,
+        source: io::Error,
+    },
+    /// `line` counts from 1 like editors do, `file` is only known when parsing a file
+    Line {
+        file: Option<PathBuf>,
+        line: usize,
+        text: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Io { file, source } => {
+                write!(f, "couldn't read {}: {}", file.display(), source)
+            }
+            ParseError::Line {
+                file,
+                line,
+                text,
+                source,
+            } => {
+                match file {
+                    Some(file) => write!(f, "{}:{}", file.display(), line)?,
+                    None => write!(f, "line {}", line)?,
+                }
+                write!(f, ": couldn't parse '{}': {}", text, source)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Io { source, .. } => Some(source),
+            ParseError::Line { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+/// Splits text into blocks separated by one or more blank lines.
+/// Blocks don't contain the trailing newline, `\r\n` line endings are accepted.
+pub fn split_sections(text: &str) -> Vec<String> {
+    let mut sections = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                sections.push(current.join("\n"));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        sections.push(current.join("\n"));
+    }
+    sections
+}
+
+/// Returns an Iterator over the blank-line separated blocks of the file.
+///
+/// Example usage:
+/// ```ignore
+/// let mut sections = aoc_utils::reader::read_sections("./inputs/day5.prod")?;
+/// let rules = sections.next().unwrap();
+/// let updates = sections.next().unwrap();
+/// ```
+pub fn read_sections<P>(filename: P) -> io::Result<std::vec::IntoIter<String>>
+where
+    P: AsRef<Path>,
+{
+    Ok(split_sections(&fs::read_to_string(filename)?).into_iter())
+}
+
+/// Reads a file made of exactly two blank-line separated blocks, parsing the first one
+/// as `A` and the second one as `B`.
+///
+/// Example usage:
+/// ```ignore
+/// let (grid, moves): (Grid<char>, Moves) = read_two_sections("./inputs/day15.prod")?;
+/// ```
+pub fn read_two_sections<A, B, P>(filename: P) -> Result<(A, B), SectionError>
+where
+    A: FromStr,
+    A::Err: Into<Box<dyn Error + Send + Sync>>,
+    B: FromStr,
+    B::Err: Into<Box<dyn Error + Send + Sync>>,
+    P: AsRef<Path>,
+{
+    let text = fs::read_to_string(filename).map_err(SectionError::Io)?;
+    parse_two_sections(&text)
+}
+
+/// In-memory version of `read_two_sections`
+pub fn parse_two_sections<A, B>(text: &str) -> Result<(A, B), SectionError>
+where
+    A: FromStr,
+    A::Err: Into<Box<dyn Error + Send + Sync>>,
+    B: FromStr,
+    B::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    let sections = split_sections(text);
+    if sections.len() != 2 {
+        return Err(SectionError::Count {
+            expected: 2,
+            found: sections.len(),
+        });
+    }
+    let a = parse_section(&sections[0], 0)?;
+    let b = parse_section(&sections[1], 1)?;
+    Ok((a, b))
+}
+
+fn parse_section<T>(text: &str, section: usize) -> Result<T, SectionError>
+where
+    T: FromStr,
+    T::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    text.parse().map_err(|e: T::Err| SectionError::Parse {
+        section,
+        source: e.into(),
+    })
+}
+
+#[derive(Debug)]
+pub enum SectionError {
+    Io(io::Error),
+    /// The input didn't have the expected number of blocks
+    Count {
+        expected: usize,
+        found: usize,
+    },
+    /// Block number `section` (counting from 0) couldn't be parsed
+    Parse {
+        section: usize,
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl fmt::Display for SectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SectionError::Io(e) => write!(f, "couldn't read input: {}", e),
+            SectionError::Count { expected, found } => {
+                write!(f, "expected {} sections, found {}", expected, found)
+            }
+            SectionError::Parse { section, source } => {
+                write!(f, "couldn't parse section {}: {}", section + 1, source)
+            }
+        }
+    }
+}
+
+impl Error for SectionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SectionError::Io(e) => Some(e),
+            SectionError::Count { .. } => None,
+            SectionError::Parse { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    fn temp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc_reader_{}_{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn lines_are_parsed() {
+        let path = temp_file("ok", "1\n-2\n3\n");
+        let numbers: Vec<i32> = parse_lines(&path).unwrap();
+        assert_eq!(numbers, vec![1, -2, 3]);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn bad_line_is_located() {
+        let path = temp_file("bad", "1\n2\nthree\n4\n");
+        let err = parse_lines::<u32, _>(&path).unwrap_err();
+        match &err {
+            ParseError::Line { line, text, .. } => {
+                assert_eq!(*line, 3);
+                assert_eq!(text, "three");
+            }
+            ParseError::Io { .. } => panic!("expected a line error"),
+        }
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{}:3: couldn't parse 'three': invalid digit found in string",
+                path.display()
+            )
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn bad_line_of_a_str_is_located() {
+        let numbers: Vec<i64> = parse_lines_str("7\n-8").unwrap();
+        assert_eq!(numbers, vec![7, -8]);
+        let err = parse_lines_str::<u8>("1\n300\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: couldn't parse '300': number too large to fit in target type"
+        );
+        let halves = parse_lines_with("4\n6\n", |line| line.parse::<u8>().map(|n| n / 2));
+        assert_eq!(halves.unwrap(), vec![2, 3]);
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let err = parse_lines::<u32, _>("./does/not/exist").unwrap_err();
+        assert!(matches!(err, ParseError::Io { .. }));
+    }
+
+    #[test]
+    fn sections_are_split_on_blank_lines() {
+        let text = "a\nb\n\n\nc\r\n \r\nd\ne\n";
+        assert_eq!(split_sections(text), vec!["a\nb", "c", "d\ne"]);
+        assert!(split_sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn two_typed_sections() {
+        let (grid, n): (Grid<char>, u32) = parse_two_sections("#.\n.#\n\n42\n").unwrap();
+        assert_eq!(grid.width(), 2);
+        assert_eq!(n, 42);
+    }
+
+    #[test]
+    fn failing_section_is_reported() {
+        let err = parse_two_sections::<Grid<char>, u32>("#.\n.#\n\nx").unwrap_err();
+        assert!(matches!(err, SectionError::Parse { section: 1, .. }));
+        assert_eq!(
+            err.to_string(),
+            "couldn't parse section 2: invalid digit found in string"
+        );
+        let err = parse_two_sections::<Grid<char>, u32>("#.\n.\n\n1").unwrap_err();
+        assert!(matches!(err, SectionError::Parse { section: 0, .. }));
+        let err = parse_two_sections::<u32, u32>("1\n\n2\n\n3").unwrap_err();
+        assert!(matches!(
+            err,
+            SectionError::Count {
+                expected: 2,
+                found: 3
+            }
+        ));
+    }
 }
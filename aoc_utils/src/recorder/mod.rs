@@ -0,0 +1,230 @@
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::grid::{write_ppm, Grid, Renderer};
+
+/// Snapshot of a simulation grid taken at `step`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame<T> {
+    pub step: usize,
+    pub grid: Grid<T>,
+}
+
+type Filter<T> = Box<dyn Fn(&Grid<T>) -> bool>;
+
+/// Collects grid snapshots during a simulation so they can be replayed or written out later
+/// instead of printing the grid after every move.
+///
+/// Example usage:
+/// ```
+/// use aoc_utils::{grid::Grid, recorder::Recorder};
+///
+/// let mut grid = Grid::filled(3, 1, '.');
+/// let mut recorder = Recorder::new().every(2);
+/// for step in 0..3 {
+///     grid[(0, step)] = '#';
+///     recorder.record(step, &grid);
+/// }
+/// assert_eq!(recorder.frames().len(), 2);
+/// ```
+pub struct Recorder<T> {
+    frames: Vec<Frame<T>>,
+    every: usize,
+    steps: Option<HashSet<usize>>,
+    filter: Option<Filter<T>>,
+}
+
+impl<T> Default for Recorder<T> {
+    fn default() -> Self {
+        Recorder {
+            frames: Vec::new(),
+            every: 1,
+            steps: None,
+            filter: None,
+        }
+    }
+}
+
+impl<T: Clone> Recorder<T> {
+    /// Records every step
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only records steps divisible by `n`
+    pub fn every(mut self, n: usize) -> Self {
+        assert!(n > 0, "Can't record every 0 steps");
+        self.every = n;
+        self
+    }
+
+    /// Only records the given steps
+    pub fn at(mut self, steps: impl IntoIterator<Item = usize>) -> Self {
+        self.steps = Some(steps.into_iter().collect());
+        self
+    }
+
+    /// Only keeps frames where `keep` holds for the grid
+    pub fn only_when(mut self, keep: impl Fn(&Grid<T>) -> bool + 'static) -> Self {
+        self.filter = Some(Box::new(keep));
+        self
+    }
+
+    /// Whether `step` would be recorded, ignoring the `only_when` filter.
+    /// Lets a simulation skip building the grid for steps that are dropped anyway.
+    pub fn wants(&self, step: usize) -> bool {
+        step.is_multiple_of(self.every) && self.steps.as_ref().is_none_or(|s| s.contains(&step))
+    }
+
+    /// Copies `grid` if the step is selected and passes the filter, returns whether it was kept
+    pub fn record(&mut self, step: usize, grid: &Grid<T>) -> bool {
+        if !self.wants(step) || self.filter.as_ref().is_some_and(|keep| !keep(grid)) {
+            return false;
+        }
+        self.frames.push(Frame {
+            step,
+            grid: grid.clone(),
+        });
+        true
+    }
+
+    pub fn frames(&self) -> &[Frame<T>] {
+        &self.frames
+    }
+
+    /// Draws every frame in place, waiting `delay` between frames
+    pub fn replay(
+        &self,
+        out: &mut impl Write,
+        cell: impl Fn(&T) -> char,
+        delay: Duration,
+    ) -> io::Result<()> {
+        for frame in &self.frames {
+            // clear the screen and move the cursor home
+            write!(out, "\x1b[2J\x1b[H")?;
+            writeln!(out, "step {}", frame.step)?;
+            Renderer::new(&frame.grid, &cell).write_to(out)?;
+            out.flush()?;
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+
+    /// Writes `frame_00000.txt`, `frame_00001.txt`, ... into `dir`, numbered in recording order
+    pub fn write_text_frames(
+        &self,
+        dir: impl AsRef<Path>,
+        cell: impl Fn(&T) -> char,
+    ) -> io::Result<Vec<PathBuf>> {
+        self.write_frames(dir.as_ref(), "txt", |frame, out| {
+            writeln!(out, "step {}", frame.step)?;
+            Renderer::new(&frame.grid, &cell).write_to(out)
+        })
+    }
+
+    /// Writes `frame_00000.ppm`, `frame_00001.ppm`, ... into `dir`, numbered in recording order
+    pub fn write_ppm_frames(
+        &self,
+        dir: impl AsRef<Path>,
+        color: impl Fn(&T) -> [u8; 3],
+    ) -> io::Result<Vec<PathBuf>> {
+        self.write_frames(dir.as_ref(), "ppm", |frame, out| {
+            write_ppm(&frame.grid, out, &color)
+        })
+    }
+
+    fn write_frames(
+        &self,
+        dir: &Path,
+        extension: &str,
+        write: impl Fn(&Frame<T>, &mut BufWriter<File>) -> io::Result<()>,
+    ) -> io::Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)?;
+        let mut paths = Vec::with_capacity(self.frames.len());
+        for (i, frame) in self.frames.iter().enumerate() {
+            let path = dir.join(format!("frame_{:05}.{}", i, extension));
+            let mut out = BufWriter::new(File::create(&path)?);
+            write(frame, &mut out)?;
+            out.flush()?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simulate(recorder: &mut Recorder<u8>) {
+        let mut grid = Grid::filled(4, 1, 0u8);
+        for step in 0..4 {
+            grid[(0, step)] = 1;
+            recorder.record(step, &grid);
+        }
+    }
+
+    #[test]
+    fn selection_and_filter() {
+        let mut all = Recorder::new();
+        simulate(&mut all);
+        assert_eq!(all.frames().len(), 4);
+
+        let mut chosen = Recorder::new().at([1, 3, 7]);
+        simulate(&mut chosen);
+        let steps: Vec<usize> = chosen.frames().iter().map(|f| f.step).collect();
+        assert_eq!(steps, vec![1, 3]);
+
+        let mut filtered = Recorder::new()
+            .only_when(|g: &Grid<u8>| g.cells().iter().filter(|&&c| c == 1).count() >= 3);
+        simulate(&mut filtered);
+        let steps: Vec<usize> = filtered.frames().iter().map(|f| f.step).collect();
+        assert_eq!(steps, vec![2, 3]);
+        assert_eq!(filtered.frames()[0].grid.cells(), &[1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn replay_draws_every_frame() {
+        let mut recorder = Recorder::new().every(2);
+        simulate(&mut recorder);
+        let mut out = Vec::new();
+        recorder
+            .replay(
+                &mut out,
+                |&c| if c == 1 { '#' } else { '.' },
+                Duration::ZERO,
+            )
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(
+            out,
+            "\x1b[2J\x1b[Hstep 0\n#...\n\x1b[2J\x1b[Hstep 2\n###.\n"
+        );
+    }
+
+    #[test]
+    fn writes_numbered_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc_recorder_{}", std::process::id()));
+        let mut recorder = Recorder::new().at([1, 3]);
+        simulate(&mut recorder);
+        let txt = recorder
+            .write_text_frames(&dir, |&c| if c == 1 { '#' } else { '.' })
+            .unwrap();
+        let ppm = recorder
+            .write_ppm_frames(&dir, |&c| [c * 255, 0, 0])
+            .unwrap();
+        assert_eq!(
+            txt,
+            vec![dir.join("frame_00000.txt"), dir.join("frame_00001.txt")]
+        );
+        assert_eq!(fs::read_to_string(&txt[1]).unwrap(), "step 3\n####\n");
+        assert!(fs::read_to_string(&ppm[0])
+            .unwrap()
+            .starts_with("P3\n4 1\n"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
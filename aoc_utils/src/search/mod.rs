@@ -0,0 +1,397 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Result of a successful search: total cost and every state from start to goal (inclusive)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Cost of an edge or path. `Default` has to be the zero cost.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// Seen states with the index of the state they were reached from
+struct Visited<S> {
+    states: Vec<S>,
+    parents: Vec<usize>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new(start: S) -> Self {
+        Visited {
+            states: vec![start.clone()],
+            parents: vec![usize::MAX],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+
+    fn push(&mut self, state: S, parent: usize) -> usize {
+        let i = self.states.len();
+        self.index.insert(state.clone(), i);
+        self.states.push(state);
+        self.parents.push(parent);
+        i
+    }
+
+    /// Walks the parents back from `i` to the start
+    fn path(&self, mut i: usize) -> Vec<S> {
+        let mut path = vec![self.states[i].clone()];
+        while self.parents[i] != usize::MAX {
+            i = self.parents[i];
+            path.push(self.states[i].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth first search where every step costs 1, `cost` is the number of steps
+pub fn bfs<S, FN, IN, FG>(start: S, mut successors: FN, mut success: FG) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some((i, steps)) = queue.pop_front() {
+        if success(&visited.states[i]) {
+            return Some(Path {
+                cost: steps,
+                states: visited.path(i),
+            });
+        }
+        for next in successors(&visited.states[i]) {
+            if !visited.index.contains_key(&next) {
+                let n = visited.push(next, i);
+                queue.push_back((n, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest path to a state satisfying `success`, `successors` returns (state, cost of the move)
+pub fn dijkstra<S, C, FN, IN, FG>(start: S, successors: FN, success: FG) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// Dijkstra guided by `heuristic`, which must never overestimate the remaining cost
+pub fn astar<S, C, FN, IN, FH, FG>(
+    start: S,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FG,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new(start);
+    let mut costs = vec![C::default()];
+    let mut pq = BinaryHeap::new();
+    pq.push(Reverse((heuristic(&visited.states[0]), C::default(), 0)));
+    while let Some(Reverse((_, cost, i))) = pq.pop() {
+        if cost > costs[i] {
+            // a cheaper way to this state was already expanded
+            continue;
+        }
+        if success(&visited.states[i]) {
+            return Some(Path {
+                cost,
+                states: visited.path(i),
+            });
+        }
+        for (next, step) in successors(&visited.states[i]) {
+            let next_cost = cost + step;
+            let n = match visited.index.entry(next) {
+                Entry::Vacant(e) => {
+                    let next = e.key().clone();
+                    let n = visited.push(next, i);
+                    costs.push(next_cost);
+                    n
+                }
+                Entry::Occupied(e) => {
+                    let n = *e.get();
+                    if next_cost >= costs[n] {
+                        continue;
+                    }
+                    costs[n] = next_cost;
+                    visited.parents[n] = i;
+                    n
+                }
+            };
+            let estimate = next_cost + heuristic(&visited.states[n]);
+            pq.push(Reverse((estimate, next_cost, n)));
+        }
+    }
+    None
+}
+
+/// Every minimum-cost path to the goal, stored as a DAG of predecessors
+#[derive(Debug, Clone)]
+pub struct AllPaths<S, C> {
+    pub cost: C,
+    states: Vec<S>,
+    /// Every state the index was reached from at its minimum cost
+    parents: Vec<Vec<usize>>,
+    /// Goal states reached at `cost`
+    goals: Vec<usize>,
+}
+
+impl<S: Clone + Eq + Hash, C> AllPaths<S, C> {
+    /// Goal states reachable at the optimal cost
+    pub fn goals(&self) -> impl Iterator<Item = &S> + '_ {
+        self.goals.iter().map(|&i| &self.states[i])
+    }
+
+    /// Number of distinct optimal paths from the start to any goal
+    pub fn count_paths(&self) -> usize {
+        // counts[i] = number of optimal paths from the start to i
+        let mut counts: Vec<Option<usize>> = vec![None; self.states.len()];
+        counts[0] = Some(1);
+        let mut stack = self.goals.clone();
+        while let Some(&i) = stack.last() {
+            if counts[i].is_some() {
+                stack.pop();
+                continue;
+            }
+            let missing: Vec<usize> = self.parents[i]
+                .iter()
+                .copied()
+                .filter(|&p| counts[p].is_none())
+                .collect();
+            if missing.is_empty() {
+                counts[i] = Some(self.parents[i].iter().map(|&p| counts[p].unwrap()).sum());
+                stack.pop();
+            } else {
+                stack.extend(missing);
+            }
+        }
+        self.goals.iter().map(|&g| counts[g].unwrap()).sum()
+    }
+
+    /// Every state that lies on at least one optimal path, start and goals included
+    pub fn states_on_paths(&self) -> HashSet<S> {
+        let mut seen = vec![false; self.states.len()];
+        let mut stack = self.goals.clone();
+        let mut res = HashSet::new();
+        while let Some(i) = stack.pop() {
+            if seen[i] {
+                continue;
+            }
+            seen[i] = true;
+            res.insert(self.states[i].clone());
+            stack.extend(self.parents[i].iter().copied());
+        }
+        res
+    }
+
+    /// Up to `k` optimal paths, each one from start to goal
+    pub fn paths(&self, k: usize) -> Vec<Vec<S>> {
+        let mut res = Vec::new();
+        // partial paths walked backwards from a goal
+        let mut stack: Vec<Vec<usize>> = self.goals.iter().map(|&g| vec![g]).collect();
+        while res.len() < k {
+            let Some(path) = stack.pop() else {
+                break;
+            };
+            let last = *path.last().unwrap();
+            if last == 0 {
+                res.push(path.iter().rev().map(|&i| self.states[i].clone()).collect());
+                continue;
+            }
+            for &p in self.parents[last].iter().rev() {
+                let mut next = path.clone();
+                next.push(p);
+                stack.push(next);
+            }
+        }
+        res
+    }
+}
+
+/// Dijkstra that keeps every predecessor reaching a state at its minimum cost, so all optimal
+/// paths to the goal can be queried instead of just one
+pub fn dijkstra_all<S, C, FN, IN, FG>(
+    start: S,
+    mut successors: FN,
+    mut success: FG,
+) -> Option<AllPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    let mut index: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    let mut parents: Vec<Vec<usize>> = vec![vec![]];
+    let mut costs = vec![C::default()];
+    let mut goals = Vec::new();
+    let mut best: Option<C> = None;
+    let mut pq = BinaryHeap::from([Reverse((C::default(), 0))]);
+    while let Some(Reverse((cost, i))) = pq.pop() {
+        if best.is_some_and(|b| cost > b) {
+            break;
+        }
+        if cost > costs[i] {
+            continue;
+        }
+        if success(&states[i]) {
+            if !goals.contains(&i) {
+                goals.push(i);
+            }
+            best = Some(cost);
+            continue;
+        }
+        for (next, step) in successors(&states[i]) {
+            let next_cost = cost + step;
+            match index.entry(next) {
+                Entry::Vacant(e) => {
+                    let n = states.len();
+                    states.push(e.key().clone());
+                    e.insert(n);
+                    parents.push(vec![i]);
+                    costs.push(next_cost);
+                    pq.push(Reverse((next_cost, n)));
+                }
+                Entry::Occupied(e) => {
+                    let n = *e.get();
+                    if next_cost < costs[n] {
+                        costs[n] = next_cost;
+                        parents[n] = vec![i];
+                        pq.push(Reverse((next_cost, n)));
+                    } else if next_cost == costs[n] && !parents[n].contains(&i) {
+                        parents[n].push(i);
+                    }
+                }
+            }
+        }
+    }
+    Some(AllPaths {
+        cost: best?,
+        states,
+        parents,
+        goals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Point};
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.##...#E";
+
+    fn open_neighbors(grid: &Grid<char>, p: Point) -> Vec<(Point, usize)> {
+        grid.neighbors4(p)
+            .filter(|(_, _, &c)| c != '#')
+            .map(|(_, n, _)| (n, 1))
+            .collect()
+    }
+
+    #[test]
+    fn bfs_finds_shortest_path() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let path = bfs(
+            (0, 0),
+            |&p| open_neighbors(&grid, p).into_iter().map(|(n, _)| n),
+            |&p| grid[p] == 'E',
+        )
+        .unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.states.len(), 13);
+        assert_eq!(path.states.first(), Some(&(0, 0)));
+        assert_eq!(path.states.last(), Some(&(3, 7)));
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let goal = (3, 7);
+        let d = dijkstra((0, 0), |&p| open_neighbors(&grid, p), |&p| p == goal).unwrap();
+        let a = astar(
+            (0, 0),
+            |&p| open_neighbors(&grid, p),
+            |&(r, c)| goal.0.abs_diff(r) + goal.1.abs_diff(c),
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(d.cost, 12);
+        assert_eq!(a.cost, 12);
+        for w in d.states.windows(2) {
+            assert_eq!(w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1), 1);
+        }
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_longer_route() {
+        // 0 -> 2 directly costs 10, going through 1 costs 2
+        let edges = |&s: &u32| match s {
+            0 => vec![(2, 10), (1, 1)],
+            1 => vec![(2, 1)],
+            _ => vec![],
+        };
+        let path = dijkstra(0, edges, |&s| s == 2).unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.states, vec![0, 1, 2]);
+        assert_eq!(dijkstra(0, edges, |&s| s == 3), None);
+    }
+
+    #[test]
+    fn all_optimal_paths() {
+        // two equally cheap ways around the wall, plus a dead end
+        let grid: Grid<char> = "\
+S..
+.#.
+..E
+..#"
+        .parse()
+        .unwrap();
+        let all = dijkstra_all((0, 0), |&p| open_neighbors(&grid, p), |&p| grid[p] == 'E').unwrap();
+        assert_eq!(all.cost, 4);
+        assert_eq!(all.goals().collect::<Vec<_>>(), vec![&(2, 2)]);
+        assert_eq!(all.count_paths(), 2);
+        let on_paths = all.states_on_paths();
+        assert_eq!(on_paths.len(), 8);
+        assert!(!on_paths.contains(&(3, 0)));
+        let paths = all.paths(5);
+        assert_eq!(paths.len(), 2);
+        assert!(paths.iter().all(|p| p.len() == 5 && p[0] == (0, 0)));
+        assert_eq!(all.paths(1).len(), 1);
+    }
+
+    #[test]
+    fn count_paths_through_grid() {
+        // every monotone path through an open 3x3 grid is optimal: C(4, 2)
+        let grid: Grid<char> = "S..\n...\n..E".parse().unwrap();
+        let all = dijkstra_all((0, 0), |&p| open_neighbors(&grid, p), |&p| grid[p] == 'E').unwrap();
+        assert_eq!(all.count_paths(), 6);
+        assert_eq!(all.states_on_paths().len(), 9);
+    }
+}
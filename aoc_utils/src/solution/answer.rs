@@ -0,0 +1,176 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use super::Unsolved;
+use crate::grid::Renderer;
+
+/// What a part returns, whatever its type was, so answers of all days compare the same way.
+/// Numbers are equal across widths and signedness, `Signed(7) == Unsigned(7)`.
+///
+/// Example usage:
+/// ```
+/// use aoc_utils::solution::Answer;
+///
+/// assert_eq!(Answer::from(7usize), Answer::from(7i32));
+/// assert_eq!("-3".parse::<Answer>().unwrap(), Answer::from(-3isize));
+/// assert_eq!("4,6,3".parse::<Answer>().unwrap(), Answer::from("4,6,3"));
+/// ```
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+    /// Letters drawn over several lines, kept without trailing spaces or blank lines
+    Art(String),
+}
+
+impl Answer {
+    /// Text art, the margins around it are dropped so it compares by the drawing only
+    pub fn art(text: &str) -> Self {
+        let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+        let first = lines
+            .iter()
+            .position(|l| !l.is_empty())
+            .unwrap_or(lines.len());
+        let last = lines
+            .iter()
+            .rposition(|l| !l.is_empty())
+            .map_or(first, |l| l + 1);
+        Answer::Art(lines[first..last].join("\n"))
+    }
+
+    /// Art doesn't fit on the line of a label, so it should be printed below it
+    pub fn is_art(&self) -> bool {
+        matches!(self, Answer::Art(_))
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(u), Answer::Signed(s)) | (Answer::Signed(s), Answer::Unsigned(u)) => {
+                u128::try_from(*s).is_ok_and(|s| s == *u)
+            }
+            (Answer::Text(a), Answer::Text(b)) | (Answer::Art(a), Answer::Art(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Text(s) | Answer::Art(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Reads an expected answer: integers become numbers, several lines become art and
+/// anything else is text
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().contains('\n') {
+            return Ok(Answer::art(s));
+        }
+        let s = s.trim();
+        Ok(if let Ok(n) = s.parse() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::Signed(n)
+        } else {
+            Answer::Text(s.to_string())
+        })
+    }
+}
+
+macro_rules! from_ints {
+    ($variant:ident, $wide:ty, $($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(n: $int) -> Self {
+                Answer::$variant(n as $wide)
+            }
+        })*
+    };
+}
+
+from_ints!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+from_ints!(Signed, i128, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl<T> From<Renderer<'_, T>> for Answer {
+    fn from(renderer: Renderer<'_, T>) -> Self {
+        Answer::art(&renderer.render_string())
+    }
+}
+
+/// Only there so `Unsolved` can be the answer type of a missing part, it never is returned
+impl From<Unsolved> for Answer {
+    fn from(unsolved: Unsolved) -> Self {
+        Answer::Text(unsolved.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn numbers_equal_across_widths() {
+        assert_eq!(Answer::from(42u8), Answer::from(42i64));
+        assert_eq!(Answer::from(u128::MAX), Answer::from(u128::MAX));
+        assert_ne!(Answer::from(-1i32), Answer::from(u128::MAX));
+        assert_ne!(Answer::from(-1i8), Answer::from(1u8));
+        assert_ne!(Answer::from(12usize), Answer::from("12"));
+    }
+
+    #[test]
+    fn parse_expected() {
+        assert_eq!(
+            "  1234\n".parse::<Answer>().unwrap(),
+            Answer::Unsigned(1234)
+        );
+        assert_eq!("-20".parse::<Answer>().unwrap(), Answer::Signed(-20));
+        assert_eq!(
+            "170141183460469231731687303715884105728"
+                .parse::<Answer>()
+                .unwrap(),
+            Answer::from(i128::MAX as u128 + 1)
+        );
+        assert_eq!(
+            "4,6,3,5".parse::<Answer>().unwrap(),
+            Answer::Text("4,6,3,5".to_string())
+        );
+        let art: Answer = "\n#..#  \n####\n#..#\n\n".parse().unwrap();
+        assert!(art.is_art());
+        assert_eq!(art.to_string(), "#..#\n####\n#..#");
+    }
+
+    #[test]
+    fn rendered_art() {
+        let grid = Grid::new(3, 2, vec![true, false, true, true, true, true]);
+        let answer = Answer::from(Renderer::new(&grid, |&on| if on { '#' } else { ' ' }));
+        assert_eq!(answer, Answer::art("# #\n###\n"));
+        assert_eq!(Answer::art(" # \n"), Answer::Art(" #".to_string()));
+    }
+}
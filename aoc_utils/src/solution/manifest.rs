@@ -0,0 +1,205 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use toml::{Table, Value};
+
+use super::{Answer, Part};
+
+/// The known correct answers, read from `answers.toml`. Tables are keyed by year, day and
+/// input kind and hold `part1` and `part2`. Numbers too big for TOML and text art go in
+/// strings, they are read like `Answer::from_str`. Answers of parts that aren't solved yet
+/// go under `pending`, they are only checked once the part is solved.
+///
+/// Example usage:
+/// ```
+/// use aoc_utils::solution::{Answer, Manifest, Part};
+///
+/// let manifest = Manifest::parse(
+///     r#"
+/// [2024.17.test]
+/// part1 = "4,6,3,5,6,3,5,2,1,0"
+///
+/// [pending.2024.17.test2]
+/// part2 = 117440
+/// "#,
+/// )
+/// .unwrap();
+/// assert_eq!(manifest.expected(2024, 17, "test2", Part::Two), None);
+/// assert_eq!(
+///     manifest.pending(2024, 17, "test2", Part::Two),
+///     Some(&Answer::from(117440u32))
+/// );
+/// assert_eq!(manifest.inputs(2024, 17), ["test", "test2"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    answers: Answers,
+    pending: Answers,
+}
+
+type Answers = BTreeMap<(u16, u8, String, Part), Answer>;
+
+impl Manifest {
+    /// A missing file is an empty manifest, every answer is then missing
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).with_context(|| format!("in {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).context(format!("couldn't read {}", path.display())),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut table: Table = text.parse()?;
+        let mut manifest = Manifest::default();
+        if let Some(pending) = table.remove("pending") {
+            read_answers(as_table(&pending, "pending")?, &mut manifest.pending)
+                .context("in pending")?;
+        }
+        read_answers(&table, &mut manifest.answers)?;
+        Ok(manifest)
+    }
+
+    pub fn expected(&self, year: u16, day: u8, input: &str, part: Part) -> Option<&Answer> {
+        self.answers.get(&(year, day, input.to_string(), part))
+    }
+
+    /// The answer of a part that isn't solved yet
+    pub fn pending(&self, year: u16, day: u8, input: &str, part: Part) -> Option<&Answer> {
+        self.pending.get(&(year, day, input.to_string(), part))
+    }
+
+    /// Input kinds that have at least one answer for the day, pending or not, sorted
+    pub fn inputs(&self, year: u16, day: u8) -> Vec<&str> {
+        let inputs: BTreeSet<&str> = self
+            .answers
+            .keys()
+            .chain(self.pending.keys())
+            .filter(|(y, d, _, _)| (*y, *d) == (year, day))
+            .map(|(_, _, input, _)| input.as_str())
+            .collect();
+        inputs.into_iter().collect()
+    }
+}
+
+fn read_answers(table: &Table, answers: &mut Answers) -> Result<()> {
+    for (year, days) in table {
+        let year: u16 = year.parse().context("years must be numbers")?;
+        for (day, inputs) in as_table(days, &year.to_string())? {
+            let day: u8 = day.parse().context("days must be numbers")?;
+            for (input, parts) in as_table(inputs, &format!("{}.{}", year, day))? {
+                let key = format!("{}.{}.{}", year, day, input);
+                for (part, answer) in as_table(parts, &key)? {
+                    let part = match part.as_str() {
+                        "part1" => Part::One,
+                        "part2" => Part::Two,
+                        _ => bail!("{}: expected part1 or part2, found {}", key, part),
+                    };
+                    let answer = match answer {
+                        Value::Integer(n) => Answer::from(*n),
+                        Value::String(s) => s.parse().unwrap(),
+                        _ => bail!("{}.{}: answers are integers or strings", key, part),
+                    };
+                    answers.insert((year, day, input.clone(), part), answer);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn as_table<'a>(value: &'a Value, key: &str) -> Result<&'a Table> {
+    match value {
+        Value::Table(table) => Ok(table),
+        _ => bail!("{} should be a table", key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_manifest() {
+        let manifest = Manifest::parse(
+            r#"
+[2023.10.test]
+part1 = 4
+
+[2023.10.test2]
+part1 = 8
+
+[2025.9.prod]
+part1 = "340282366920938463463374607431768211455"
+part2 = -12
+
+[2022.10.prod]
+part2 = """
+#..#
+####
+"""
+"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.inputs(2023, 10), ["test", "test2"]);
+        assert_eq!(
+            manifest.expected(2023, 10, "test2", Part::One),
+            Some(&Answer::from(8u8))
+        );
+        assert_eq!(manifest.expected(2023, 10, "test2", Part::Two), None);
+        assert_eq!(
+            manifest.expected(2025, 9, "prod", Part::One),
+            Some(&Answer::from(u128::MAX))
+        );
+        assert_eq!(
+            manifest.expected(2025, 9, "prod", Part::Two),
+            Some(&Answer::from(-12isize))
+        );
+        assert!(manifest
+            .expected(2022, 10, "prod", Part::Two)
+            .is_some_and(Answer::is_art));
+        assert!(manifest.inputs(2024, 1).is_empty());
+    }
+
+    #[test]
+    fn pending_answers() {
+        let manifest = Manifest::parse(
+            r#"
+[2023.10.test3]
+part1 = 4
+
+[pending.2023.10.test3]
+part2 = 10
+
+[pending.2023.11.test]
+part2 = 1030
+"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.expected(2023, 10, "test3", Part::Two), None);
+        assert_eq!(
+            manifest.pending(2023, 10, "test3", Part::Two),
+            Some(&Answer::from(10u8))
+        );
+        assert_eq!(manifest.pending(2023, 10, "test3", Part::One), None);
+        assert_eq!(manifest.inputs(2023, 11), ["test"]);
+        assert!(Manifest::parse("[pending.2023.10.test]\npart3 = 1").is_err());
+        assert!(Manifest::parse("pending = 1").is_err());
+    }
+
+    #[test]
+    fn malformed_manifest() {
+        assert!(Manifest::parse("[2024.1.test]\npart3 = 1").is_err());
+        assert!(Manifest::parse("[2024.first.test]\npart1 = 1").is_err());
+        assert!(Manifest::parse("[2024.1]\ntest = 1").is_err());
+        assert!(Manifest::parse("[2024.1.test]\npart1 = 1.5").is_err());
+        assert_eq!(
+            Manifest::load("does/not/exist.toml").unwrap(),
+            Manifest::default()
+        );
+    }
+}
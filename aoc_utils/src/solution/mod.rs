@@ -0,0 +1,265 @@
+//! The interface every day implements, so one `aoc` binary per year can run all of them.
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+mod answer;
+mod manifest;
+
+pub use answer::Answer;
+pub use manifest::Manifest;
+
+/// One day of a year. Parsing is kept apart from the parts so it is done once for both
+/// parts and can be timed on its own.
+///
+/// Example usage:
+/// ```
+/// use aoc_utils::solution::Solution;
+///
+/// struct Day1;
+///
+/// impl Solution for Day1 {
+///     type Input = Vec<u32>;
+///     type Answer1 = u32;
+///     type Answer2 = u32;
+///
+///     fn parse(input: &str) -> anyhow::Result<Self::Input> {
+///         Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+///     }
+///
+///     fn part1(input: &Self::Input) -> anyhow::Result<u32> {
+///         Ok(input.iter().sum())
+///     }
+///
+///     fn part2(input: &Self::Input) -> anyhow::Result<u32> {
+///         Ok(input.iter().max().copied().unwrap_or(0))
+///     }
+/// }
+///
+/// assert_eq!(Day1::part1(&Day1::parse("1\n2\n3").unwrap()).unwrap(), 6);
+/// ```
+pub trait Solution {
+    type Input;
+    type Answer1: Into<Answer> + 'static;
+    /// `Unsolved` until part 2 is done
+    type Answer2: Into<Answer> + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+
+    /// Fails with `Unsolved` unless it is implemented
+    fn part2(_input: &Self::Input) -> Result<Self::Answer2> {
+        Err(Unsolved.into())
+    }
+
+    /// Reads and parses `file` then solves part 1, meant for the tests
+    fn solve_part1(file: impl AsRef<Path>) -> Result<Self::Answer1> {
+        Self::part1(&Self::parse(&fs::read_to_string(file)?)?)
+    }
+
+    /// Reads and parses `file` then solves part 2, meant for the tests
+    fn solve_part2(file: impl AsRef<Path>) -> Result<Self::Answer2> {
+        Self::part2(&Self::parse(&fs::read_to_string(file)?)?)
+    }
+}
+
+/// A part that has no solution yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not solved yet")
+    }
+}
+
+impl Error for Unsolved {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part {}", self.number())
+    }
+}
+
+/// Answer of one part together with how long parsing and solving took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// A registered solution with its types erased, so a whole year fits in one list.
+/// Usually made by `solutions!`.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    run: fn(&str, Part) -> Result<Run>,
+    solved: fn(Part) -> bool,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(day: u8) -> Self {
+        Day {
+            day,
+            run: run::<S>,
+            solved: solved::<S>,
+        }
+    }
+
+    /// A part whose answer type is `Unsolved` isn't done, known without any input
+    pub fn is_solved(&self, part: Part) -> bool {
+        (self.solved)(part)
+    }
+
+    /// Parses `input` and solves `part` of it. A part without a solution fails with
+    /// `Unsolved`, check with `is_unsolved`.
+    pub fn run(&self, input: &str, part: Part) -> Result<Run> {
+        (self.run)(input, part)
+    }
+}
+
+/// Whether `err` comes from a part that isn't implemented rather than a failed one
+pub fn is_unsolved(err: &anyhow::Error) -> bool {
+    err.is::<Unsolved>()
+}
+
+fn solved<S: Solution>(part: Part) -> bool {
+    let answer = match part {
+        Part::One => TypeId::of::<S::Answer1>(),
+        Part::Two => TypeId::of::<S::Answer2>(),
+    };
+    answer != TypeId::of::<Unsolved>()
+}
+
+fn run<S: Solution>(input: &str, part: Part) -> Result<Run> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    let answer = match part {
+        Part::One => S::part1(&parsed)?.into(),
+        Part::Two => S::part2(&parsed)?.into(),
+    };
+    Ok(Run {
+        answer,
+        parse,
+        solve: start.elapsed(),
+    })
+}
+
+/// Registers the days of a year crate as `pub const SOLUTIONS: &[Day]`, in the order given
+///
+/// Example usage:
+/// ```ignore
+/// pub mod day1;
+/// pub mod day2;
+///
+/// aoc_utils::solutions! {
+///     1 => day1::Day1,
+///     2 => day2::Day2,
+/// }
+/// ```
+#[macro_export]
+macro_rules! solutions {
+    ($($day:literal => $solution:ty),* $(,)?) => {
+        pub const SOLUTIONS: &[$crate::solution::Day] =
+            &[$($crate::solution::Day::new::<$solution>($day)),*];
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+        type Answer1 = i64;
+        type Answer2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(crate::parse::ints(input)?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<i64> {
+            Ok(input.iter().sum())
+        }
+    }
+
+    struct Words;
+
+    impl Solution for Words {
+        type Input = String;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.trim().to_string())
+        }
+
+        fn part1(input: &Self::Input) -> Result<usize> {
+            Ok(input.split_whitespace().count())
+        }
+
+        fn part2(input: &Self::Input) -> Result<String> {
+            Ok(input.split_whitespace().rev().collect::<Vec<_>>().join(","))
+        }
+    }
+
+    solutions! {
+        1 => Sum,
+        3 => Words,
+    }
+
+    #[test]
+    fn registered_days_run() {
+        assert_eq!(SOLUTIONS.iter().map(|d| d.day).collect::<Vec<_>>(), [1, 3]);
+        assert_eq!(
+            SOLUTIONS[0].run("1 -2 7", Part::One).unwrap().answer,
+            Answer::from(6u8)
+        );
+        assert_eq!(
+            SOLUTIONS[1].run("a b c\n", Part::Two).unwrap().answer,
+            Answer::from("c,b,a")
+        );
+    }
+
+    #[test]
+    fn missing_part_is_unsolved() {
+        let err = SOLUTIONS[0].run("1 2", Part::Two).unwrap_err();
+        assert!(is_unsolved(&err));
+        assert!(SOLUTIONS[0].is_solved(Part::One));
+        assert!(!SOLUTIONS[0].is_solved(Part::Two));
+        assert!(SOLUTIONS[1].is_solved(Part::Two));
+        // parsing comes first, so a bad input is still reported as such
+        let err = SOLUTIONS[0]
+            .run("99999999999999999999", Part::Two)
+            .unwrap_err();
+        assert!(!is_unsolved(&err));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    aoc_utils::cli::main(2022, rust_2022::SOLUTIONS)
+}
@@ -0,0 +1,32 @@
+use anyhow::Result;
+use aoc_utils::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// Calories carried by every elf
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        Ok(input
+            .split("\n\n")
+            .map(|x| x.lines().flat_map(str::parse::<usize>).sum())
+            .collect_vec())
+    }
+
+    fn part1(input: &Vec<usize>) -> Result<usize> {
+        Ok(*input.iter().max().unwrap())
+    }
+
+    fn part2(input: &Vec<usize>) -> Result<usize> {
+        let mut maxes = input.clone();
+        maxes.sort_by(|a, b| b.cmp(a));
+
+        let sum: usize = maxes.iter().take(3).sum();
+
+        Ok(sum)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod day1;
+
+aoc_utils::solutions! {
+    1 => day1::Day1,
+}
@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    aoc_utils::cli::main(2023, rust_2023::SOLUTIONS)
+}
@@ -1,10 +1,20 @@
 use anyhow::Result;
-use std::fs;
+use aoc_utils::solution::Solution;
 
 const DIGITS_SPELLED: &[&str] = &[
     "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
+fn get_digit(line: &str, reverse: bool) -> char {
+    let idx: usize;
+    if reverse {
+        idx = line.rfind(|c: char| c.is_digit(10)).unwrap();
+    } else {
+        idx = line.find(|c: char| c.is_digit(10)).unwrap();
+    }
+    return line.chars().nth(idx).unwrap();
+}
+
 fn match_spld_digits(spelled: &str) -> char {
     return match spelled {
         "one" => '1',
@@ -51,7 +61,8 @@ fn find_spelled_idx(line: &str, reverse: bool, idx: &mut usize) -> Option<usize>
     return Some(found_len);
 }
 
-fn get_digit(line: &str, reverse: bool) -> char {
+/// Like `get_digit` but digits can also be spelled out
+fn get_spelled_digit(line: &str, reverse: bool) -> char {
     let mut idx: usize;
     if reverse {
         idx = line.rfind(|c: char| c.is_digit(10)).unwrap_or_default();
@@ -67,8 +78,8 @@ fn get_digit(line: &str, reverse: bool) -> char {
     };
 }
 
-fn main() -> Result<()> {
-    let sum: i32 = fs::read_to_string("./inputs/day1.prod")?
+fn calibration(input: &str, get_digit: fn(&str, bool) -> char) -> i32 {
+    input
         .lines()
         .map(|line| {
             let mut digits = String::from(get_digit(line, false));
@@ -76,8 +87,25 @@ fn main() -> Result<()> {
 
             return digits.parse::<i32>().unwrap();
         })
-        .sum();
-    println!("{}", sum);
+        .sum()
+}
 
-    return Ok(());
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = String;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String) -> Result<i32> {
+        Ok(calibration(input, get_digit))
+    }
+
+    fn part2(input: &String) -> Result<i32> {
+        Ok(calibration(input, get_spelled_digit))
+    }
 }
@@ -0,0 +1,161 @@
+use anyhow::Result;
+use aoc_utils::{
+    grid::{Direction, Vec2},
+    solution::{Solution, Unsolved},
+};
+use itertools::Itertools;
+
+/// The compass points the pipe on `tile` connects
+fn match_direction(tile: &char) -> Vec<Vec2> {
+    let connects = match tile {
+        '|' => "NS",
+        '-' => "EW",
+        'L' => "NE",
+        'J' => "NW",
+        '7' => "SW",
+        'F' => "SE",
+        '.' => "",
+        'S' => "NESW",
+        _ => panic!("Wrong character in input :("),
+    };
+    connects
+        .chars()
+        .map(|c| Direction::try_from(c).unwrap().resolve())
+        .collect()
+}
+
+/// The positions the pipe at `pos` leads to that are neither seen yet nor ground
+fn filter_options(
+    maze: &Vec<Vec<char>>,
+    pos: (usize, usize),
+    opts: &Vec<Vec2>,
+    seen: &Vec<Vec<bool>>,
+) -> Vec<(usize, usize)> {
+    let max_row = maze.len();
+    let max_col = maze.get(0).unwrap().len();
+    let mut new_opts = Vec::new();
+    for &direction in opts.iter() {
+        //index out of bound
+        let Some(new_pos) = (Vec2::from(pos) + direction).to_point_within(max_row, max_col) else {
+            continue;
+        };
+        //has seen
+        if *has_seen(seen, new_pos) {
+            continue;
+        }
+        //ground
+        if get_tile(maze, new_pos) == &'.' {
+            continue;
+        }
+        new_opts.push(new_pos);
+    }
+    return new_opts;
+}
+
+fn move_opts(maze: &Vec<Vec<char>>, pos: (usize, usize)) -> Vec<Vec2> {
+    let tile = get_tile(maze, pos);
+    let moves_opts = match_direction(tile);
+    return moves_opts;
+}
+fn walk(
+    maze: &Vec<Vec<char>>,
+    pos: (usize, usize),
+    seen: &mut Vec<Vec<bool>>,
+    mut max: usize,
+    counter: usize,
+) -> usize {
+    // println!("walk pos: {:?}", pos);
+    //check if already seen point
+    if counter > 1 && get_tile(maze, pos) == &'S' {
+        return counter;
+    }
+    // if *has_seen(seen, pos) {
+    //     return 1;
+    // }
+    let directions = move_opts(maze, pos);
+    // println!("{:?} before filter {:?}", pos, directions);
+    let next = filter_options(maze, pos, &directions, seen);
+    // println!(
+    //     "for pos:{:?} those directions are available:{:?}",
+    //     pos, directions
+    // );
+    if counter > max {
+        max = counter.to_owned();
+    }
+    //mark position as seen
+    *seen.get_mut(pos.0).unwrap().get_mut(pos.1).unwrap() = true;
+    // println!("{:?}", seen);
+
+    for new_pos in next {
+        // println!(
+        //     "Walking from {:?} to {:?}",
+        //     get_tile(maze, pos),
+        //     get_tile(maze, new_pos)
+        // );
+        let candidate_max = walk(maze, new_pos, seen, max, counter + 1);
+        if candidate_max > max {
+            max = candidate_max;
+        }
+    }
+
+    return max;
+}
+
+fn has_seen(seen: &Vec<Vec<bool>>, pos: (usize, usize)) -> &bool {
+    return seen.get(pos.0).unwrap().get(pos.1).unwrap();
+}
+
+fn get_tile(maze: &Vec<Vec<char>>, pos: (usize, usize)) -> &char {
+    return maze.get(pos.0).unwrap().get(pos.1).unwrap();
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    /// The maze with the start position
+    type Input = (Vec<Vec<char>>, (usize, usize));
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(binding: &str) -> Result<Self::Input> {
+        let input = binding.lines().collect_vec();
+        let start = find_start_pos(&input);
+        let input: Vec<Vec<char>> = binding
+            .lines()
+            .map(|line| line.chars().collect_vec())
+            .collect_vec();
+        Ok((input, start))
+    }
+
+    fn part1((input, start): &Self::Input) -> Result<usize> {
+        // println!("{:?}", input);
+
+        let (rows, cols): (usize, usize) = get_row_col_length(input);
+        let mut seen = vec![vec![false; cols]; rows];
+        let max = walk(input, *start, &mut seen, 0, 1);
+
+        // println!("max: {:?}", max/2);
+
+        Ok(max / 2)
+    }
+}
+
+fn find_start_pos(input: &Vec<&str>) -> (usize, usize) {
+    let mut start = (0, 0);
+    for (row, line) in input.iter().enumerate() {
+        match line.find('S') {
+            Some(col) => {
+                start = (row, col);
+                break;
+            }
+            None => continue,
+        }
+    }
+    return start;
+}
+
+fn get_row_col_length(maze: &Vec<Vec<char>>) -> (usize, usize) {
+    let rows = maze.len();
+    let cols = maze.get(0).unwrap().len();
+    return (rows, cols);
+}
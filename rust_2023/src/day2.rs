@@ -1,26 +1,27 @@
 use anyhow::Result;
+use aoc_utils::solution::Solution;
 use itertools::Itertools;
 
-use std::{fs, str::FromStr};
+use std::str::FromStr;
 
-const MAX_RED: u8 = 12;
-const MAX_GREEN: u8 = 13;
-const MAX_BLUE: u8 = 14;
+const MAX_RED: usize = 12;
+const MAX_GREEN: usize = 13;
+const MAX_BLUE: usize = 14;
 
 #[derive(Debug, PartialEq)]
 struct Reveal {
-    red: u8,
-    green: u8,
-    blue: u8,
+    red: usize,
+    green: usize,
+    blue: usize,
 }
 
 impl FromStr for Reveal {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let mut red: u8 = 0;
-        let mut green: u8 = 0;
-        let mut blue: u8 = 0;
+        let mut red: usize = 0;
+        let mut green: usize = 0;
+        let mut blue: usize = 0;
         s.split(",").for_each(|cube_type| {
             cube_type
                 .trim()
@@ -34,23 +35,26 @@ impl FromStr for Reveal {
                 });
         });
 
-
         return Ok(Reveal { red, blue, green });
     }
 }
 
 #[derive(Debug, PartialEq)]
-struct Game {
+pub struct Game {
     id: usize,
-    max_red: u8,
-    max_blue: u8,
-    max_green: u8,
+    max_red: usize,
+    max_blue: usize,
+    max_green: usize,
 }
 
 impl Game {
     fn is_game_possible(&self) -> bool {
         return self.max_red <= MAX_RED && self.max_green <= MAX_GREEN && self.max_blue <= MAX_BLUE;
     }
+    /// The fewest cubes that make the game possible are the most ever revealed
+    fn get_cube_set(&self) -> usize {
+        return self.max_red * self.max_green * self.max_blue;
+    }
 }
 
 impl FromStr for Game {
@@ -60,9 +64,9 @@ impl FromStr for Game {
         //"Game <num>:"
         let colon_idx = s.find(":").unwrap();
         let id: usize = s[5..colon_idx].parse().unwrap();
-        let mut max_red: u8 = 0;
-        let mut max_blue: u8 = 0;
-        let mut max_green: u8 = 0;
+        let mut max_red: usize = 0;
+        let mut max_blue: usize = 0;
+        let mut max_green: usize = 0;
         //"Game <num>: "
         s[colon_idx + 1..].split(";").for_each(|rev| {
             let reveal: Reveal = rev.trim().parse().unwrap();
@@ -80,24 +84,37 @@ impl FromStr for Game {
     }
 }
 
-fn compare_and_set_max(value: &u8, max: &mut u8) {
+fn compare_and_set_max(value: &usize, max: &mut usize) {
     if value > max {
         *max = *value;
     }
 }
 
-fn main() -> Result<()> {
-    let sum: usize = fs::read_to_string("./inputs/day2.prod")?
-        .lines()
-        .map(|line| {
-            let game: Game = line.parse().unwrap();
-            if game.is_game_possible() {
-                return game.id;
-            }
-            return 0;
-        })
-        .sum();
-
-    println!("{sum}");
-    Ok(())
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Game>> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(input: &Vec<Game>) -> Result<usize> {
+        let sum = input
+            .iter()
+            .map(|game| {
+                if game.is_game_possible() {
+                    return game.id;
+                }
+                return 0;
+            })
+            .sum();
+        Ok(sum)
+    }
+
+    fn part2(input: &Vec<Game>) -> Result<usize> {
+        Ok(input.iter().map(|game| game.get_cube_set()).sum())
+    }
 }
@@ -0,0 +1,61 @@
+use anyhow::Result;
+use aoc_utils::solution::Solution;
+use itertools::Itertools;
+
+fn count_matches(line: &str) -> usize {
+    let numbers: Vec<&str> = line[7..].split("|").collect_vec();
+    let mut matches: usize = 0;
+    for winner in numbers.get(0).unwrap().trim().split(" ") {
+        for scratched in numbers.get(1).unwrap().trim().split(" ") {
+            if scratched.eq("") {
+                continue;
+            }
+            if winner == scratched {
+                matches += 1;
+            }
+        }
+    }
+    return matches;
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    /// How many winning numbers every card has
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        Ok(input.lines().map(count_matches).collect())
+    }
+
+    fn part1(input: &Vec<usize>) -> Result<usize> {
+        let sum = input
+            .iter()
+            .map(|&matches| {
+                let mut sum: usize = 0;
+                for _ in 0..matches {
+                    if sum == 0 {
+                        sum = 1;
+                    } else {
+                        sum *= 2;
+                    }
+                }
+                return sum;
+            })
+            .sum();
+        Ok(sum)
+    }
+
+    fn part2(input: &Vec<usize>) -> Result<usize> {
+        let mut card_copies: Vec<usize> = vec![1; input.len()];
+        for (idx, &matches) in input.iter().enumerate() {
+            let copies_of_current_card = card_copies[idx];
+            for i in idx + 1..idx + 1 + matches {
+                card_copies[i] += copies_of_current_card;
+            }
+        }
+        Ok(card_copies.iter().sum())
+    }
+}
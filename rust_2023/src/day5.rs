@@ -1,9 +1,10 @@
 use anyhow::Result;
+use aoc_utils::solution::Solution;
 use itertools::Itertools;
-use std::{collections::HashMap, fs};
+use std::collections::HashMap;
 
 #[derive(Clone, Eq, Hash, PartialEq, Debug, PartialOrd, Ord)]
-enum Category {
+pub enum Category {
     SeedToSoil,
     SoilToFertilizer,
     FertilizerToWater,
@@ -52,7 +53,7 @@ impl Seed {
 }
 
 #[derive(Ord, PartialOrd, PartialEq, Eq, Debug, Clone)]
-struct MapRange {
+pub struct MapRange {
     destination_range: RangeTuple,
     source_range: RangeTuple,
 }
@@ -134,6 +135,24 @@ fn parse_seed_ids(line: &str, seeds_vec: &mut Vec<Seed>) {
     }
 }
 
+fn parse_seed_ids_only(line: &str) -> Vec<usize> {
+    let mut seeds: Vec<usize> = Vec::new();
+    let colon_idx = line.find(":").unwrap();
+    let line = &line[colon_idx + 1..];
+    let trimmed_line = line.trim();
+    trimmed_line
+        .split(" ")
+        .tuples()
+        .for_each(|(start_seed_id, range)| {
+            let start_seed_id = start_seed_id.parse::<usize>().unwrap();
+            let range = range.parse::<usize>().unwrap();
+            for id in start_seed_id..start_seed_id + range {
+                seeds.push(id)
+            }
+        });
+    return seeds;
+}
+
 fn match_category(line: &str) -> Category {
     return match line {
         "seed-to-soil map:" => Category::SeedToSoil,
@@ -147,55 +166,100 @@ fn match_category(line: &str) -> Category {
     };
 }
 
-fn main() -> Result<()> {
-    let input_lines = fs::read_to_string("./inputs/day5.prod")?;
-    let input_lines = input_lines.trim().split("\n\n").collect_vec();
-    let mut seeds: Vec<Seed> = Vec::new();
-    parse_seed_ids(input_lines.get(0).unwrap(), &mut seeds);
-    let mut category_maps: HashMap<Category, Vec<MapRange>> = HashMap::new();
-    input_lines.iter().skip(1).for_each(|map_and_data| {
-        let map = map_and_data.split("\n").collect_vec();
-        let category = match_category(map.get(0).unwrap());
-        map.iter().skip(1).for_each(|range| {
-            let map_range: MapRange = MapRange::new(range);
-            match category_maps.get_mut(&category) {
-                Some(ranges) => ranges.push(map_range),
-                None => {
-                    let _ = category_maps.insert(category.clone(), vec![map_range]);
+type CategoryMaps = HashMap<Category, Vec<MapRange>>;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    /// The seeds line and the ranges of every category
+    type Input = (String, CategoryMaps);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input_lines = input.trim().split("\n\n").collect_vec();
+        let mut category_maps: CategoryMaps = HashMap::new();
+        input_lines.iter().skip(1).for_each(|map_and_data| {
+            let map = map_and_data.split("\n").collect_vec();
+            let category = match_category(map.get(0).unwrap());
+            map.iter().skip(1).for_each(|range| {
+                let map_range: MapRange = MapRange::new(range);
+                match category_maps.get_mut(&category) {
+                    //TODO_HERE probably here we could to the comparison
+                    //instead of pushing to the vec
+                    //for context look TODO comment below
+                    Some(ranges) => ranges.push(map_range),
+                    None => {
+                        let _ = category_maps.insert(category.clone(), vec![map_range]);
+                    }
                 }
-            }
-        })
-    });
-    let mut min_location: usize = usize::MAX;
-    seeds.iter_mut().for_each(|seed| {
-        category_maps
-            .iter_mut()
-            .sorted()
-            .for_each(|(category, ranges)| {
-                for range in ranges {
-                    let in_range_pos: InRangeDecisionPosition = range
-                        .get_dest_pos_from_src(seed.get_relevant_position_for_category(category));
-                    if in_range_pos.is_in_range {
-                        //If it is in the range break the loop
+            })
+        });
+        Ok((input_lines.get(0).unwrap().to_string(), category_maps))
+    }
+
+    fn part1((seeds_line, category_maps): &Self::Input) -> Result<usize> {
+        let mut seeds: Vec<Seed> = Vec::new();
+        parse_seed_ids(seeds_line, &mut seeds);
+        let mut min_location: usize = usize::MAX;
+        seeds.iter_mut().for_each(|seed| {
+            category_maps
+                .iter()
+                .sorted()
+                .for_each(|(category, ranges)| {
+                    for range in ranges {
+                        let in_range_pos: InRangeDecisionPosition = range.get_dest_pos_from_src(
+                            seed.get_relevant_position_for_category(category),
+                        );
+                        if in_range_pos.is_in_range {
+                            //If it is in the range break the loop
+                            seed.set_position_to_category(category, in_range_pos.position);
+                            break;
+                        }
+                        //Loop over all ranges to check if it is there
+                        // It is going to do some overwrite but that's to be though about later
                         seed.set_position_to_category(category, in_range_pos.position);
-                        break;
                     }
-                    //Loop over all ranges to check if it is there
-                    // It is going to do some overwrite but that's to be though about later
-                    seed.set_position_to_category(category, in_range_pos.position);
-                }
-                if category == &Category::HumidityToLocation {
-                    min_location = std::cmp::min(seed.location, min_location);
-                }
-            });
-    });
-    // for seed in seeds{
-    // println!("{:?}", seed);
-    // }
-    //
-    println!("Min location: {}", min_location);
-
-    Ok(())
+                    if category == &Category::HumidityToLocation {
+                        min_location = std::cmp::min(seed.location, min_location);
+                    }
+                });
+        });
+        Ok(min_location)
+    }
+
+    fn part2((seeds_line, category_maps): &Self::Input) -> Result<usize> {
+        let seed_ids: Vec<usize> = parse_seed_ids_only(seeds_line);
+        let mut min_location: usize = usize::MAX;
+        let mut seed_id_tmp: usize;
+        //TODO This can be optimized so well, for example it could be put into the
+        //part where ranges are extracted (look TODO_HERE comment)
+        //but it's 1am, i want to go to sleep
+        for seed_id in seed_ids {
+            seed_id_tmp = seed_id;
+            category_maps
+                .iter()
+                .sorted()
+                .for_each(|(category, ranges)| {
+                    for range in ranges {
+                        let in_range_pos: InRangeDecisionPosition =
+                            range.get_dest_pos_from_src(seed_id_tmp);
+                        if in_range_pos.is_in_range {
+                            //If it is in the range break the loop
+                            seed_id_tmp = in_range_pos.position;
+                            break;
+                        }
+                        //Loop over all ranges to check if it is there
+                        // It is going to do some overwrite but that's to be though about later
+                        seed_id_tmp = in_range_pos.position;
+                    }
+                    if category == &Category::HumidityToLocation {
+                        min_location = std::cmp::min(seed_id_tmp, min_location);
+                    }
+                });
+        }
+        Ok(min_location)
+    }
 }
 
 impl Default for Seed {
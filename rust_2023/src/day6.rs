@@ -0,0 +1,79 @@
+use anyhow::Result;
+use aoc_utils::solution::Solution;
+use itertools::Itertools;
+
+fn ways_to_win(x: usize, y: usize) -> usize {
+    let mut ways_counter = 0;
+    for speed in 0..x {
+        let remaining_race_time = x - speed;
+        match remaining_race_time * speed > y {
+            true => {
+                ways_counter += 1;
+            }
+            false => (),
+        }
+    }
+    ways_counter
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String) -> Result<usize> {
+        let mut ways = 1;
+        input
+            .lines()
+            .map(|x| {
+                return x
+                    .split(" ")
+                    .filter(|s| return !(s.is_empty() || s.eq(&" ")))
+                    .skip(1)
+                    .map(|n| {
+                        return n.parse::<usize>().unwrap();
+                    })
+                    .collect_vec();
+            })
+            .tuples()
+            .for_each(|(times, distances)| {
+                times.iter().zip(distances.iter()).for_each(|(x, y)| {
+                    let ways_counter = ways_to_win(*x, *y);
+                    if ways_counter != 0 {
+                        ways *= ways_counter;
+                    }
+                });
+            });
+        Ok(ways)
+    }
+
+    /// There is only one race, the spaces between the digits are bad kerning
+    fn part2(input: &String) -> Result<usize> {
+        let mut ways = 1;
+        input
+            .lines()
+            .map(|x| {
+                let number = x.find(|c: char| c.is_digit(10)).unwrap();
+                return x[number..]
+                    .split(" ")
+                    .filter(|s| !(s.is_empty() || s.eq(&"")))
+                    .join("")
+                    .parse::<usize>()
+                    .unwrap();
+            })
+            .tuples()
+            .for_each(|(x, y)| {
+                let ways_counter = ways_to_win(x, y);
+                if ways_counter != 0 {
+                    ways *= ways_counter;
+                }
+            });
+        Ok(ways)
+    }
+}
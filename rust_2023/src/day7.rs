@@ -1,6 +1,7 @@
 use anyhow::Result;
+use aoc_utils::solution::Solution;
 use itertools::Itertools;
-use std::{cmp::Ordering, fs, str::FromStr};
+use std::{cmp::Ordering, str::FromStr};
 
 #[derive(Clone, Debug, PartialEq)]
 enum HandType {
@@ -25,17 +26,23 @@ impl HandType {
             HandType::FiveOfAKind => 6,
         };
     }
-    fn three_of_kind_or_two_pair(card_counts: &Vec<(usize, char)>, wildcard_count:usize) -> HandType {
+    fn three_of_kind_or_two_pair(
+        card_counts: &Vec<(usize, char)>,
+        wildcard_count: usize,
+    ) -> HandType {
         for (count, _card) in card_counts {
-            if *count+wildcard_count == 3 {
+            if *count + wildcard_count == 3 {
                 return HandType::ThreeOfAKind;
             }
         }
         return Self::TwoPair;
     }
-    fn four_of_kind_or_full_house(card_counts: &Vec<(usize, char)>, wildcard_count:usize) -> HandType {
+    fn four_of_kind_or_full_house(
+        card_counts: &Vec<(usize, char)>,
+        wildcard_count: usize,
+    ) -> HandType {
         for (count, _card) in card_counts {
-            if *count+wildcard_count == 4 {
+            if *count + wildcard_count == 4 {
                 return Self::FourOfAKind;
             }
         }
@@ -43,12 +50,11 @@ impl HandType {
     }
 }
 
-impl FromStr for HandType {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+impl HandType {
+    /// With `jokers` every J counts as whatever card makes the best hand
+    fn new(s: &str, jokers: bool) -> HandType {
         let card_counts = s.chars().sorted().dedup_with_count().collect_vec();
-        let has_wildcard = s.contains('J');
+        let has_wildcard = jokers && s.contains('J');
         let length;
         let mut wildcard_count = 0;
         if has_wildcard {
@@ -63,7 +69,7 @@ impl FromStr for HandType {
         } else {
             length = card_counts.len()
         }
-        let htype = match length {
+        return match length {
             5 => HandType::HighCard,
             4 => HandType::OnePair,
             3 => HandType::three_of_kind_or_two_pair(&card_counts, wildcard_count),
@@ -72,15 +78,13 @@ impl FromStr for HandType {
             0 => HandType::FiveOfAKind,
             _ => panic!("Couldnt get hand type from length={}, s={:?}", length, s),
         };
-        return Ok(htype);
     }
 }
 
 #[derive(Clone, Debug)]
-struct HandBid {
+pub struct HandBid {
     hand: String,
     bid: usize,
-    hand_type: HandType,
 }
 
 impl FromStr for HandBid {
@@ -88,21 +92,21 @@ impl FromStr for HandBid {
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         let data = s.split_whitespace().collect_vec();
-        let hand_type = HandType::from_str(data.get(0).unwrap()).unwrap();
         let hand: String = data.get(0).unwrap().to_string();
         return Ok(Self {
             hand,
             bid: s[6..].parse().unwrap(),
-            hand_type,
         });
     }
 }
 
-fn get_card_value(card: &char) -> u8 {
+fn get_card_value(card: &char, jokers: bool) -> u8 {
     return match card {
         'A' => 14,
         'K' => 13,
         'Q' => 12,
+        'J' if jokers => 1,
+        'J' => 11,
         'T' => 10,
         '9' => 9,
         '8' => 8,
@@ -112,7 +116,6 @@ fn get_card_value(card: &char) -> u8 {
         '4' => 4,
         '3' => 3,
         '2' => 2,
-        'J' => 1,
         _ => panic!("bad card here {:?}", card),
     };
 }
@@ -128,12 +131,12 @@ fn sort_by_type(h1: &HandType, h2: &HandType) -> Ordering {
     Ordering::Equal
 }
 
-fn sort_by_cards(h1: &String, h2: &String) -> Ordering {
+fn sort_by_cards(h1: &String, h2: &String, jokers: bool) -> Ordering {
     let iter = h1.chars().zip(h2.chars());
     for (c1, c2) in iter {
         if c1 == c2 {
             continue;
-        } else if get_card_value(&c1) > get_card_value(&c2) {
+        } else if get_card_value(&c1, jokers) > get_card_value(&c2, jokers) {
             return Ordering::Greater;
         } else {
             return Ordering::Less;
@@ -142,21 +145,33 @@ fn sort_by_cards(h1: &String, h2: &String) -> Ordering {
     Ordering::Equal
 }
 
-fn main() -> Result<()> {
-    let sum: usize = fs::read_to_string("./inputs/day7.prod")?
-        .lines()
-        .map(|hand_bid| {
-            let hand_bid = HandBid::from_str(hand_bid).unwrap();
-            return hand_bid;
-        })
-        .sorted_by(|h1: &HandBid, h2: &HandBid| sort_by_cards(&h1.hand, &h2.hand))
-        .sorted_by(|h1: &HandBid, h2: &HandBid| sort_by_type(&h1.hand_type, &h2.hand_type))
+fn total_winnings(hands: &Vec<HandBid>, jokers: bool) -> usize {
+    hands
+        .iter()
+        .map(|hand_bid| (hand_bid, HandType::new(&hand_bid.hand, jokers)))
+        .sorted_by(|(h1, _), (h2, _)| sort_by_cards(&h1.hand, &h2.hand, jokers))
+        .sorted_by(|(_, t1), (_, t2)| sort_by_type(t1, t2))
         .enumerate()
-        .map(|(idx, hand)| {
-            return hand.bid * (idx + 1 as usize);
-        })
-        .sum();
-    println!("{}", sum);
+        .map(|(idx, (hand, _))| hand.bid * (idx + 1 as usize))
+        .sum()
+}
+
+pub struct Day7;
 
-    Ok(())
+impl Solution for Day7 {
+    type Input = Vec<HandBid>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<HandBid>> {
+        input.lines().map(HandBid::from_str).collect()
+    }
+
+    fn part1(input: &Vec<HandBid>) -> Result<usize> {
+        Ok(total_winnings(input, false))
+    }
+
+    fn part2(input: &Vec<HandBid>) -> Result<usize> {
+        Ok(total_winnings(input, true))
+    }
 }
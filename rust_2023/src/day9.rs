@@ -0,0 +1,68 @@
+use anyhow::Result;
+use aoc_utils::solution::Solution;
+use itertools::Itertools;
+
+fn is_vec_only_zeros(x: &Vec<isize>) -> bool {
+    for y in x.iter() {
+        if *y != 0 as isize {
+            return false;
+        }
+    }
+    return true;
+}
+
+fn next_value_in_history(history: Vec<isize>) -> isize {
+    if is_vec_only_zeros(&history) {
+        return *history.last().unwrap();
+    }
+    let history2 = history
+        .iter()
+        .tuple_windows()
+        .map(|(x, y)| y - x)
+        .collect_vec();
+    return history.last().unwrap() + next_value_in_history(history2);
+}
+
+fn first_value_in_history(history: Vec<isize>) -> isize {
+    if is_vec_only_zeros(&history) {
+        return *history.last().unwrap();
+    }
+    let history2 = history
+        .iter()
+        .tuple_windows()
+        .map(|(x, y)| x - y)
+        .collect_vec();
+    return history.first().unwrap() + first_value_in_history(history2);
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<isize>>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|x| {
+                x.split_whitespace()
+                    .map(|y| {
+                        return y.trim().parse::<isize>().unwrap();
+                    })
+                    .collect_vec()
+            })
+            .collect_vec())
+    }
+
+    fn part1(input: &Self::Input) -> Result<isize> {
+        Ok(input.iter().map(|x| next_value_in_history(x.clone())).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<isize> {
+        Ok(input
+            .iter()
+            .map(|x| first_value_in_history(x.clone()))
+            .sum())
+    }
+}
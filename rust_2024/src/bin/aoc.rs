@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    aoc_utils::cli::main(2024, rust_2024::SOLUTIONS)
+}
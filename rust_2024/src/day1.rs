@@ -0,0 +1,51 @@
+use anyhow::Result;
+use aoc_utils::solution::Solution;
+use std::collections::HashMap;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// The left and the right list
+    type Input = (Vec<usize>, Vec<usize>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        input.lines().for_each(|line| {
+            let mut split = line.trim().split("   ");
+            let left_num = split
+                .next()
+                .expect("Missing left number")
+                .parse::<usize>()
+                .expect("Couldn't parse the number");
+            left.push(left_num);
+            let right_num = split
+                .next()
+                .expect("Missing left number")
+                .parse::<usize>()
+                .expect("Couldn't parse the number");
+            right.push(right_num);
+        });
+        Ok((left, right))
+    }
+
+    fn part1((left, right): &Self::Input) -> Result<usize> {
+        let mut left = left.clone();
+        let mut right = right.clone();
+        left.sort();
+        right.sort();
+        let sum: usize = left.iter().zip(right).map(|(r, l)| r.abs_diff(l)).sum();
+        Ok(sum)
+    }
+
+    fn part2((left, right_list): &Self::Input) -> Result<usize> {
+        let mut right = HashMap::new();
+        for &right_num in right_list {
+            *right.entry(right_num).or_insert(0) += 1;
+        }
+        let sum: usize = left.iter().map(|&l| l * right.get(&l).unwrap_or(&0)).sum();
+        Ok(sum)
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use aoc_utils::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|l| {
+                l.trim()
+                    .chars()
+                    .map(|c| c.to_digit(10).unwrap() as i32)
+                    .collect_vec()
+            })
+            .collect_vec())
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        let mut trails: HashMap<(i32, i32), HashSet<(i32, i32)>> = HashMap::new();
+        let max_r = map.len();
+        let max_c = map[0].len();
+        for r in 0..max_r {
+            for c in 0..max_c {
+                let h = map[r][c];
+                if h != 0 {
+                    continue;
+                }
+                let r = r as i32;
+                let c = c as i32;
+                let start = (r, c);
+                walk_score(
+                    map,
+                    &mut trails,
+                    (r + 1, c),
+                    h,
+                    (max_r as i32, max_c as i32),
+                    start,
+                );
+                walk_score(
+                    map,
+                    &mut trails,
+                    (r - 1, c),
+                    h,
+                    (max_r as i32, max_c as i32),
+                    start,
+                );
+                walk_score(
+                    map,
+                    &mut trails,
+                    (r, c + 1),
+                    h,
+                    (max_r as i32, max_c as i32),
+                    start,
+                );
+                walk_score(
+                    map,
+                    &mut trails,
+                    (r, c - 1),
+                    h,
+                    (max_r as i32, max_c as i32),
+                    start,
+                );
+            }
+        }
+        let result: usize = trails.values().map(|s| s.len()).sum();
+        Ok(result)
+    }
+
+    fn part2(map: &Self::Input) -> Result<i32> {
+        let mut trails: HashMap<(i32, i32), i32> = HashMap::new();
+        let max_r = map.len();
+        let max_c = map[0].len();
+        for r in 0..max_r {
+            for c in 0..max_c {
+                let h = map[r][c];
+                if h != 0 {
+                    continue;
+                }
+                let r = r as i32;
+                let c = c as i32;
+                let start = (r, c);
+                walk_rating(
+                    map,
+                    &mut trails,
+                    (r + 1, c),
+                    h,
+                    (max_r as i32, max_c as i32),
+                    start,
+                );
+                walk_rating(
+                    map,
+                    &mut trails,
+                    (r - 1, c),
+                    h,
+                    (max_r as i32, max_c as i32),
+                    start,
+                );
+                walk_rating(
+                    map,
+                    &mut trails,
+                    (r, c + 1),
+                    h,
+                    (max_r as i32, max_c as i32),
+                    start,
+                );
+                walk_rating(
+                    map,
+                    &mut trails,
+                    (r, c - 1),
+                    h,
+                    (max_r as i32, max_c as i32),
+                    start,
+                );
+            }
+        }
+        let result: i32 = trails.values().sum();
+        Ok(result)
+    }
+}
+
+/// Collects the distinct tops every trailhead reaches
+fn walk_score(
+    map: &Vec<Vec<i32>>,
+    trails: &mut HashMap<(i32, i32), HashSet<(i32, i32)>>,
+    (r, c): (i32, i32),
+    prev_h: i32,
+    (max_r, max_c): (i32, i32),
+    start: (i32, i32),
+) {
+    if is_oob((r, c), (max_r, max_c)) {
+        return;
+    }
+    let h = map[r as usize][c as usize];
+    if h == 0 && r == start.0 && c == start.1 {
+        return;
+    }
+    if h == 9 && h - prev_h == 1 {
+        trails.entry(start).or_insert(HashSet::new()).insert((r, c));
+        return;
+    }
+    if h - prev_h == 1 {
+        walk_score(map, trails, (r + 1, c), h, (max_r, max_c), start);
+        walk_score(map, trails, (r - 1, c), h, (max_r, max_c), start);
+        walk_score(map, trails, (r, c + 1), h, (max_r, max_c), start);
+        walk_score(map, trails, (r, c - 1), h, (max_r, max_c), start);
+    }
+}
+
+/// Counts every distinct trail from every trailhead
+fn walk_rating(
+    map: &Vec<Vec<i32>>,
+    trails: &mut HashMap<(i32, i32), i32>,
+    (r, c): (i32, i32),
+    prev_h: i32,
+    (max_r, max_c): (i32, i32),
+    start: (i32, i32),
+) {
+    if is_oob((r, c), (max_r, max_c)) {
+        return;
+    }
+    let h = map[r as usize][c as usize];
+    if h == 0 && r == start.0 && c == start.1 {
+        return;
+    }
+    if h == 9 && h - prev_h == 1 {
+        *trails.entry(start).or_insert(0) += 1;
+        return;
+    }
+    if h - prev_h == 1 {
+        walk_rating(map, trails, (r + 1, c), h, (max_r, max_c), start);
+        walk_rating(map, trails, (r - 1, c), h, (max_r, max_c), start);
+        walk_rating(map, trails, (r, c + 1), h, (max_r, max_c), start);
+        walk_rating(map, trails, (r, c - 1), h, (max_r, max_c), start);
+    }
+}
+
+fn is_oob((r, c): (i32, i32), (max_r, max_c): (i32, i32)) -> bool {
+    r < 0 || c < 0 || r >= max_r || c >= max_c
+}
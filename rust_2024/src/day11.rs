@@ -1,16 +1,31 @@
-use std::{collections::HashMap, fs, time::Instant};
+use std::collections::HashMap;
 
 use anyhow::Result;
+use aoc_utils::solution::Solution;
 
-fn solve(file_name: &str, blinks: i32) -> Result<usize, anyhow::Error> {
-    let input = fs::read_to_string(file_name)?;
-    let stones = input
-        .trim()
-        .split(" ")
-        .map(|x| (x.parse::<u128>().unwrap(), 1))
-        .collect::<HashMap<_, _>>();
-    let res = blinking_sim(stones, blinks);
-    Ok(res)
+pub struct Day11;
+
+impl Solution for Day11 {
+    /// How many stones carry each number, their order never matters
+    type Input = HashMap<u128, usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut stones = HashMap::new();
+        for x in input.trim().split(" ") {
+            *stones.entry(x.parse::<u128>()?).or_default() += 1;
+        }
+        Ok(stones)
+    }
+
+    fn part1(stones: &Self::Input) -> Result<usize> {
+        Ok(blinking_sim(stones.clone(), 25))
+    }
+
+    fn part2(stones: &Self::Input) -> Result<usize> {
+        Ok(blinking_sim(stones.clone(), 75))
+    }
 }
 
 fn number_length(mut n: u128) -> usize {
@@ -61,23 +76,16 @@ fn blink(old_stones: &HashMap<u128, usize>) -> HashMap<u128, usize> {
     new_stones
 }
 
-fn main() -> Result<()> {
-    let files = [
-        "./inputs/day11.test",
-        "./inputs/day11.test2",
-        "./inputs/day11.prod",
-    ];
-    for file in files {
-        let now = Instant::now();
-        let res = solve(file, 75)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
-    }
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
+
+    fn solve(file_name: &str, blinks: i32) -> Result<usize> {
+        let stones = Day11::parse(&fs::read_to_string(file_name)?)?;
+        Ok(blinking_sim(stones, blinks))
+    }
+
     #[test]
     fn blink_once() {
         let file = "./inputs/day11.test";
@@ -0,0 +1,81 @@
+use anyhow::Result;
+use aoc_utils::grid::{Grid, Regions};
+use aoc_utils::solution::Solution;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Regions;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Regions> {
+        let garden: Grid<char> = input.parse()?;
+        Ok(garden.regions())
+    }
+
+    fn part1(input: &Regions) -> Result<usize> {
+        let res = input
+            .regions
+            .iter()
+            .map(|region| region.area() * region.perimeter)
+            .sum();
+        Ok(res)
+    }
+
+    fn part2(input: &Regions) -> Result<usize> {
+        let res = input
+            .regions
+            .iter()
+            .map(|region| region.area() * region.sides)
+            .sum();
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn basic_example_part_1() {
+        let file = "./inputs/day12.test";
+        let result = Day12::solve_part1(file).unwrap();
+        let expected = 140;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn basic_example_part_2() {
+        let file = "./inputs/day12.test";
+        let result = Day12::solve_part2(file).unwrap();
+        let expected = 80;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn region_inside_example() {
+        let file = "./inputs/day12.test2";
+        let result = Day12::solve_part1(file).unwrap();
+        let expected = 772;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn example_xo() {
+        let file = "./inputs/day12.test2";
+        let result = Day12::solve_part2(file).unwrap();
+        let expected = 436;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn example_part_1() {
+        let file = "./inputs/day12.test3";
+        let result = Day12::solve_part1(file).unwrap();
+        let expected = 1930;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn example_part_2() {
+        let file = "./inputs/day12.test3";
+        let result = Day12::solve_part2(file).unwrap();
+        let expected = 1206;
+        assert_eq!(result, expected)
+    }
+}
@@ -0,0 +1,174 @@
+use anyhow::{bail, Result};
+use aoc_utils::{
+    grid::{Point, Vec2},
+    parse::ints_n,
+    solution::Solution,
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    p: Vec2,
+    v: Vec2,
+}
+
+impl Robot {
+    /// Where the robot is after `seconds`, it teleports to the other side at the walls
+    fn at(&self, seconds: usize, rows: usize, cols: usize) -> Point {
+        (self.p + self.v * seconds as isize).wrap(rows, cols)
+    }
+}
+
+impl FromStr for Robot {
+    type Err = anyhow::Error;
+
+    //p=0,4 v=3,-3
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let [x, y, dx, dy] = ints_n::<isize, 4>(s)?;
+        Ok(Robot {
+            p: Vec2::new(y, x),
+            v: Vec2::new(dy, dx),
+        })
+    }
+}
+
+/// The example is a 11x7 room, the real input 101x103
+fn room_size(robots: &[Robot]) -> (usize, usize) {
+    if robots.len() < 100 {
+        (7, 11)
+    } else {
+        (103, 101)
+    }
+}
+
+fn safety_factor(input: &[Robot], rows: usize, cols: usize, seconds: usize) -> usize {
+    let mut q1 = 0;
+    let mut q2 = 0;
+    let mut q3 = 0;
+    let mut q4 = 0;
+    let vertical_line = cols / 2;
+    let horizontal_line = rows / 2;
+    for r in input {
+        let (y, x) = r.at(seconds, rows, cols);
+        if y < horizontal_line {
+            if x < vertical_line {
+                q1 += 1;
+            } else if x > vertical_line {
+                q2 += 1;
+            }
+        } else if y > horizontal_line {
+            if x < vertical_line {
+                q3 += 1;
+            } else if x > vertical_line {
+                q4 += 1;
+            }
+        }
+    }
+
+    q1 * q2 * q3 * q4
+}
+
+fn is_grid_unique(grid: &Vec<Vec<i32>>) -> bool {
+    for ele in grid {
+        for &c in ele {
+            if c > 1 {
+                return false;
+            }
+        }
+    }
+    return true;
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let (rows, cols) = room_size(input);
+        Ok(safety_factor(input, rows, cols, 100))
+    }
+
+    /// The tree shows up the first time no two robots share a tile
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let (rows, cols) = room_size(input);
+        // positions repeat after rows * cols seconds
+        for s in 1..=rows * cols {
+            let mut grid = vec![vec![0; cols]; rows];
+            for r in input {
+                let (y, x) = r.at(s, rows, cols);
+                grid[y][x] += 1;
+            }
+            if is_grid_unique(&grid) {
+                return Ok(s);
+            }
+        }
+        bail!("robots never stop overlapping")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(file_name: &str, rows: usize, cols: usize, seconds: usize) -> Result<usize> {
+        let input = Day14::parse(&std::fs::read_to_string(file_name)?)?;
+        Ok(safety_factor(&input, rows, cols, seconds))
+    }
+
+    #[test]
+    fn example() {
+        let file = "./inputs/day14.test";
+        let rows = 7;
+        let cols = 11;
+        let result = solve(file, rows, cols, 100).unwrap();
+        let expected = 12;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn test_teleporting_robot() {
+        let file = "./inputs/day14.test_single_robot";
+        let rows = 7;
+        let cols = 11;
+        let result = solve(file, rows, cols, 5).unwrap();
+        let expected = 0;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn modulo() {
+        let a: isize = -7;
+        let b = 3;
+        println!("1: {a} % {b} == {}", a % b);
+        println!(
+            "2: (rem_euclid): {a}.rem_euclid({b}) == {}",
+            a.rem_euclid(b)
+        );
+        let a: isize = 7;
+        let b = 3;
+        println!("3: {a} % {b} == {}", a % b);
+        println!(
+            "4: (rem_euclid): {a}.rem_euclid({b}) == {}",
+            a.rem_euclid(b)
+        );
+        let a: isize = -7;
+        let b = -3;
+        println!("5: {a} % {b} == {}", a % b);
+        println!(
+            "6: (rem_euclid): {a}.rem_euclid({b}) == {}",
+            a.rem_euclid(b)
+        );
+        let a: isize = -94;
+        let b = 11;
+        println!("7: {a} % {b} == {}", a % b);
+        println!(
+            "8: (rem_euclid): {a}.rem_euclid({b}) == {}",
+            a.rem_euclid(b)
+        );
+    }
+}
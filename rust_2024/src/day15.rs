@@ -0,0 +1,204 @@
+use anyhow::Result;
+use aoc_utils::grid::{Direction, Grid};
+use aoc_utils::solution::{Solution, Unsolved};
+use std::cmp;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Grid<char>, Vec<Direction>);
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (g, m) = input
+            .split_once("\n\n")
+            .expect("Wrong input, couldn't find \\n\\n");
+        let grid: Grid<char> = g.parse()?;
+        let moves = m
+            .chars()
+            .filter(|&c| c != '\n')
+            .map(Direction::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok((grid, moves))
+    }
+
+    fn part1((grid, moves): &Self::Input) -> Result<usize> {
+        let mut grid: Vec<Vec<char>> = grid.rows().map(<[char]>::to_vec).collect();
+        let mut cur_pos = find_robot(&grid);
+        grid[cur_pos.0][cur_pos.1] = '.';
+        for &m in moves {
+            cur_pos = move_robot(&mut grid, cur_pos, m);
+        }
+        Ok(sum_box_cords(&grid))
+    }
+
+    // part 2 isn't solved yet, wide boxes only move left and right
+}
+
+fn move_robot(grid: &mut Vec<Vec<char>>, (r, c): (usize, usize), m: Direction) -> (usize, usize) {
+    let (nr, nc) = m.move_point((r, c));
+    let next = grid[nr][nc];
+    //if next pos is wall return pos
+    if next == '#' {
+        return (r, c);
+    }
+    //if next pos is dot walk there
+    if next == '.' {
+        return (nr, nc);
+    }
+    //if next pos is box
+    // call move_robot with box as it's pos
+    // once it's done return the pos before
+    if next == 'O' {
+        let (nnr, nnc) = move_robot(grid, (nr, nc), m);
+        let is_wall_next = nnr == nr && nnc == nc;
+        if is_wall_next {
+            // no swaps made
+            return (r, c);
+        } else {
+            grid[nnr][nnc] = 'O';
+            grid[nr][nc] = '.';
+        }
+    }
+    (nr, nc)
+}
+
+fn sum_box_cords(grid: &Vec<Vec<char>>) -> usize {
+    let mut sum = 0;
+    grid.iter().enumerate().for_each(|(r_idx, row)| {
+        row.iter().enumerate().for_each(|(c_idx, c)| {
+            if *c == 'O' {
+                sum += 100 * r_idx + c_idx;
+            }
+        })
+    });
+    sum
+}
+
+fn _scale_wide(grid: &Grid<char>) -> Vec<Vec<char>> {
+    grid.scale_cells(|c| match c {
+        '#' => ['#', '#'],
+        'O' => ['[', ']'],
+        '.' => ['.', '.'],
+        '@' => ['@', '.'],
+        _ => panic!("WRONG CHARACTER"),
+    })
+    .rows()
+    .map(<[char]>::to_vec)
+    .collect()
+}
+
+fn _move_wide_robot(
+    grid: &mut Vec<Vec<char>>,
+    (r, c): (usize, usize),
+    m: Direction,
+) -> (usize, usize) {
+    let (nr, nc) = m.move_point((r, c));
+    let next = grid[nr][nc];
+    //if next pos is wall break
+    if next == '#' {
+        return (r, c);
+    }
+    //if next pos is dot walk there
+    if next == '.' {
+        return (nr, nc);
+    }
+    //if next pos is box
+    // call move_robot with box as it's pos
+    // once it's done return the pos before
+    //TODO: HANDLE MOVING LARGE BOXES UP n DOWN (moving left and right works)
+    if next == '[' {
+        //left bracket
+        let (lr, lc) = (nr, nc);
+        //right bracket
+        let (rr, rc) = (nr, nc + 1);
+        //next by right bracket
+        let (nrr, nrc) = _move_wide_robot(grid, (rr, rc), m);
+        let is_wall_next = nrr == rr && nrc == rc;
+        if is_wall_next {
+            // no swaps made
+            return (r, c);
+        } else {
+            grid[nrr][nrc] = ']';
+            grid[rr][rc] = '[';
+            grid[lr][lc] = '.';
+        }
+    }
+    if next == ']' {
+        //right bracket
+        let (rr, rc) = (nr, nc);
+        //left bracket
+        let (lr, lc) = (nr, nc - 1);
+        //next by left bracket
+        let (nlr, nlc) = _move_wide_robot(grid, (lr, lc), m);
+        let is_wall_next = nlr == lr && nlc == lc;
+        if is_wall_next {
+            // no swaps made
+            return (r, c);
+        } else {
+            grid[nlr][nlc] = '[';
+            grid[lr][lc] = ']';
+            grid[rr][rc] = '.';
+        }
+    }
+    (nr, nc)
+}
+
+fn _sum_wide_box_cords(grid: &Vec<Vec<char>>) -> usize {
+    let mut sum = 0;
+    let max_c = grid[0].len();
+    grid.iter().enumerate().for_each(|(r_idx, row)| {
+        row.iter().enumerate().for_each(|(c_idx, c)| {
+            if *c == '[' {
+                sum += 100 * r_idx + cmp::min(c_idx, max_c - c_idx + 1);
+            }
+        })
+    });
+    sum
+}
+
+fn find_robot(grid: &Vec<Vec<char>>) -> (usize, usize) {
+    for r in 0..grid.len() {
+        for c in 0..grid[0].len() {
+            if grid[r][c] == '@' {
+                return (r, c);
+            }
+        }
+    }
+    panic!("COULDNT FIND ROBOT");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn example_move() {
+        let file = "./inputs/day15.test_testingmoves";
+        let _result = Day15::solve_part1(file).unwrap();
+    }
+    #[test]
+    fn small_example_calc() {
+        let file = "./inputs/day15.test_calc";
+        let result = Day15::solve_part1(file).unwrap();
+        let expected = 104;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn example() {
+        let file = "./inputs/day15.test";
+        let result = Day15::solve_part1(file).unwrap();
+        let expected = 10092;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn wide_calc() {
+        let mut grid = Vec::new();
+        grid.push(vec!['#', '#', '#', '#', '#', '#', '#', '#', '#', '#']);
+        grid.push(vec!['#', '#', '.', '.', '.', '[', ']', '.', '.', '.']);
+        grid.push(vec!['#', '#', '.', '.', '.', '.', '.', '.', '.', '.']);
+        let result = _sum_wide_box_cords(&grid);
+        let expected = 105;
+        assert_eq!(result, expected)
+    }
+}
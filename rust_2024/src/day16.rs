@@ -0,0 +1,94 @@
+use anyhow::Result;
+use aoc_utils::{
+    grid::{parse_grid, Direction, Grid, Point},
+    search::{dijkstra, dijkstra_all},
+    solution::Solution,
+};
+use std::collections::HashSet;
+
+/// Moving forward costs 1, turning and moving costs 1001
+fn steps(
+    walls: &Grid<bool>,
+    (p, d): (Point, Direction),
+) -> impl Iterator<Item = ((Point, Direction), usize)> + '_ {
+    [
+        (d, 1),
+        (d.rotate_clockwise(), 1001),
+        (d.rotate_counter_clockwise(), 1001),
+    ]
+    .into_iter()
+    .map(move |(d, cost)| ((d.move_point(p), d), cost))
+    .filter(|&((p, _), _)| !walls[p])
+}
+
+fn walk(walls: &Grid<bool>, cur: Point, end: Point, dir: Direction) -> usize {
+    let path = dijkstra((cur, dir), |&s| steps(walls, s), |&(p, _)| p == end);
+    path.expect("Couldn't find the END").cost
+}
+
+/// Number of tiles that are part of at least one best path
+fn walk_all(walls: &Grid<bool>, cur: Point, end: Point, dir: Direction) -> usize {
+    let paths = dijkstra_all((cur, dir), |&s| steps(walls, s), |&(p, _)| p == end);
+    let tiles: HashSet<Point> = paths
+        .expect("Couldn't find the END")
+        .states_on_paths()
+        .into_iter()
+        .map(|(p, _)| p)
+        .collect();
+    tiles.len()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    /// Walls of the maze with the start and end tiles
+    type Input = (Grid<bool>, Point, Point);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        let (walls, [start, end]) = parse_grid(text, [('S', '.'), ('E', '.')], |c| c == '#')?;
+        Ok((walls, start, end))
+    }
+
+    fn part1((walls, start, end): &Self::Input) -> Result<usize> {
+        Ok(walk(walls, *start, *end, Direction::Right))
+    }
+
+    fn part2((walls, start, end): &Self::Input) -> Result<usize> {
+        Ok(walk_all(walls, *start, *end, Direction::Right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn example_part_1() {
+        let file = "./inputs/day16.test";
+        let result = Day16::solve_part1(file).unwrap();
+        let expected = 7036;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn example_part_2() {
+        let file = "./inputs/day16.test";
+        let result = Day16::solve_part2(file).unwrap();
+        let expected = 45;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn example_2_part_1() {
+        let file = "./inputs/day16.test2";
+        let result = Day16::solve_part1(file).unwrap();
+        let expected = 11048;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn example_2_part_2() {
+        let file = "./inputs/day16.test2";
+        let result = Day16::solve_part2(file).unwrap();
+        let expected = 64;
+        assert_eq!(result, expected)
+    }
+}
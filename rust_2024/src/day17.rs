@@ -1,7 +1,8 @@
 use anyhow::Result;
+use aoc_utils::parse::{ints, ints_n};
+use aoc_utils::solution::{Solution, Unsolved};
 use itertools::Itertools;
-use regex::Regex;
-use std::{fs, ops::BitXor, time::Instant};
+use std::ops::BitXor;
 
 #[derive(Debug)]
 enum Instruction {
@@ -15,8 +16,8 @@ enum Instruction {
     Cdv, //
 }
 
-#[derive(Debug)]
-struct Computer {
+#[derive(Debug, Clone)]
+pub struct Computer {
     register_a: usize,
     register_b: usize,
     register_c: usize,
@@ -26,17 +27,15 @@ impl Computer {
     fn insert_out(&mut self, v: usize) {
         self.out.push(v);
     }
-    fn _out_to_joined_number(self) -> usize {
+    fn _out_to_joined_number(&self) -> usize {
         self.out
-            .into_iter()
+            .iter()
             .join("")
             .parse()
             .expect("Couldnt parse out to number")
     }
-    fn out_to_comma_string(self) -> String {
-        self.out
-            .into_iter()
-            .join(",")
+    fn out_to_comma_string(&self) -> String {
+        self.out.iter().join(",")
     }
     fn compute(&mut self, programs: Vec<usize>) {
         let mut instr_ptr = 0;
@@ -124,42 +123,34 @@ impl Computer {
     }
 }
 
-type Input<T> = T;
+pub struct Day17;
 
-fn solve(file_name: &str) -> Result<String> {
-    let (mut computer, program): Input<(Computer, Vec<usize>)> = read_input(file_name)?;
-    computer.compute(program);
-    Ok(computer.out_to_comma_string())
-}
+impl Solution for Day17 {
+    type Input = (Computer, Vec<usize>);
+    type Answer1 = String;
+    // TODO: find the value for register_a so that computer.out == program
+    type Answer2 = Unsolved;
 
-fn read_input(file_name: &str) -> Result<Input<(Computer, Vec<usize>)>> {
-    let input = fs::read_to_string(file_name)?;
-    let (registers, program) = input.split_once("\n\n").expect("Wrong input!");
-    let re_registers = Regex::new(r"[0-9]+").unwrap();
-    let re_program = Regex::new(r"[0-9]").unwrap();
-    let registers = registers
-        .split("\n")
-        .map(|register| {
-            let m = re_registers
-                .find(register)
-                .expect("No number found in register")
-                .as_str();
-            m.parse::<usize>().expect("Couldn't parse number to usize")
-        })
-        .collect_vec();
-    let program = re_program
-        .find_iter(program)
-        .map(|p| p.as_str().parse::<usize>().expect("Couldn't parse digit"))
-        .collect_vec();
-    Ok((
-        Computer {
-            register_a: registers[0],
-            register_b: registers[1],
-            register_c: registers[2],
-            out: Vec::new(),
-        },
-        program,
-    ))
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (registers, program) = input.split_once("\n\n").expect("Wrong input!");
+        let [register_a, register_b, register_c] = ints_n(registers)?;
+        let program = ints(program)?;
+        Ok((
+            Computer {
+                register_a,
+                register_b,
+                register_c,
+                out: Vec::new(),
+            },
+            program,
+        ))
+    }
+
+    fn part1((computer, program): &Self::Input) -> Result<String> {
+        let mut computer = computer.clone();
+        computer.compute(program.clone());
+        Ok(computer.out_to_comma_string())
+    }
 }
 
 fn parse_opcode(op: usize) -> Instruction {
@@ -176,27 +167,13 @@ fn parse_opcode(op: usize) -> Instruction {
     }
 }
 
-fn main() -> Result<()> {
-    let files = [
-        "./inputs/day17.test",
-        "./inputs/day17.prod",
-    ];
-    for file in files {
-        let now = Instant::now();
-        let res = solve(file)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
-        println!();
-    }
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
     fn example() {
         let file = "./inputs/day17.test";
-        let result = solve(file).unwrap();
+        let result = Day17::solve_part1(file).unwrap();
         let expected = "4,6,3,5,6,3,5,2,1,0";
         assert_eq!(result, expected)
     }
@@ -266,4 +243,25 @@ mod tests {
 
         assert_eq!(c.register_b, 44354);
     }
+    #[test]
+    fn copy() {
+        let mut c = Computer {
+            register_a: 2024,
+            register_b: 0,
+            register_c: 0,
+            out: Vec::new(),
+        };
+        let p = vec![0, 3, 5, 4, 3, 0];
+        c.compute(p);
+        println!();
+        let mut c = Computer {
+            register_a: 117440,
+            register_b: 0,
+            register_c: 0,
+            out: Vec::new(),
+        };
+        let p = vec![0, 3, 5, 4, 3, 0];
+        c.compute(p.clone());
+        assert_eq!(c.out, p);
+    }
 }
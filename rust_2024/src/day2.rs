@@ -1,11 +1,16 @@
 use anyhow::Result;
+use aoc_utils::solution::Solution;
 use itertools::Itertools;
-use std::fs;
 
-fn main() -> Result<()> {
-    let files = ["./inputs/day2.test" , "./inputs/day2.prod"];
-    for file in files {
-        let count = fs::read_to_string(file)?
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<usize>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
             .lines()
             .map(|line| {
                 line.trim()
@@ -13,12 +18,19 @@ fn main() -> Result<()> {
                     .map(|x| x.parse().expect("Couldn't parse"))
                     .collect()
             })
-            .filter(|report| is_safe_with_perms(&report))
-            .count();
-        println!("{}: {}", file, count);
+            .collect())
     }
 
-    Ok(())
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(input.iter().filter(|report| is_safe(report)).count())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(input
+            .iter()
+            .filter(|report| is_safe_with_perms(report))
+            .count())
+    }
 }
 
 fn is_safe_with_perms(report: &Vec<usize>) -> bool {
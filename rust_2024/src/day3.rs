@@ -1,4 +1,6 @@
 use anyhow::Result;
+use aoc_utils::solution::Solution;
+use itertools::Itertools;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -8,7 +10,31 @@ use nom::{
     sequence::{delimited, separated_pair},
     IResult,
 };
-use std::fs;
+use regex::Regex;
+
+#[derive(Debug)]
+struct Mul {
+    x: isize,
+    y: isize,
+}
+impl Mul {
+    fn resolve(&self) -> isize {
+        self.x * self.y
+    }
+}
+
+//part 1 was solved with a regex, part 2 with nom
+fn parse_muls_regex(s: &str) -> Vec<Mul> {
+    let remuls = Regex::new(r"mul\((?<x>[0-9]{1,3}),(?<y>[0-9]{1,3})\)").unwrap();
+    remuls
+        .captures_iter(s)
+        .map(|capture| {
+            let x = capture["x"].parse::<isize>().unwrap();
+            let y = capture["y"].parse::<isize>().unwrap();
+            Mul { x, y }
+        })
+        .collect_vec()
+}
 
 #[derive(Debug)]
 enum Instruction {
@@ -38,15 +64,34 @@ fn parse_instr(s: &str) -> IResult<&str, Option<Instruction>> {
     ))(s)
 }
 
-fn main() -> Result<()> {
-    let files = [
-        "./inputs/day3.test",
-        "./inputs/day3.test2",
-        "./inputs/day3.prod",
-    ];
-    for file in files {
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// The corrupted memory, both parts scan it their own way
+    type Input = String;
+    type Answer1 = isize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String) -> Result<isize> {
+        let result: isize = input
+            .lines()
+            .map(|line| {
+                parse_muls_regex(line)
+                    .iter()
+                    .map(|mul| mul.resolve())
+                    .sum::<isize>()
+            })
+            .sum();
+        Ok(result)
+    }
+
+    fn part2(input: &String) -> Result<i32> {
         let mut enabled = true;
-        let sum = fs::read_to_string(file)?
+        let sum = input
             .lines()
             .map(|line| {
                 let (_, intrs) = many0(parse_instr)(line).unwrap();
@@ -75,7 +120,6 @@ fn main() -> Result<()> {
                     .sum::<i32>()
             })
             .sum::<i32>();
-        println!("{}: {}", file, sum);
+        Ok(sum)
     }
-    Ok(())
 }
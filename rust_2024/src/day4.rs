@@ -0,0 +1,148 @@
+use std::collections::VecDeque;
+
+use anyhow::Result;
+use aoc_utils::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<String>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(|l| String::from(l)).collect_vec())
+    }
+
+    fn part1(grid: &Self::Input) -> Result<i32> {
+        let row: i32 = grid.len().try_into().unwrap();
+        let col: i32 = grid.get(0).unwrap().len().try_into().unwrap();
+        let mut count = 0;
+        for r in 0..row {
+            let row_input = grid.get(r as usize).unwrap();
+            for c in 0..col {
+                let char = row_input.chars().nth(c as usize).unwrap();
+                if char != 'X' {
+                    continue;
+                }
+                let mut stack: VecDeque<char> = VecDeque::new();
+                for (x, y) in DIRS {
+                    count += dfs(grid, &mut stack, r, c, row, col, (*x, *y));
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<i32> {
+        let row: i32 = grid.len().try_into().unwrap();
+        let col: i32 = grid.get(0).unwrap().len().try_into().unwrap();
+        let mut count = 0;
+        for r in 0..row {
+            let row_input = grid.get(r as usize).unwrap();
+            for c in 0..col {
+                let char = row_input.chars().nth(c as usize).unwrap();
+                if char != 'A' {
+                    continue;
+                }
+                if is_x_mas(grid, r, c, row, col) {
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+}
+
+const DIRS: &'static [(i32, i32)] = &[
+    (1, 0),
+    (-1, 0),
+    (1, 1),
+    (1, -1),
+    (0, 1),
+    (0, -1),
+    (-1, -1),
+    (-1, 1),
+];
+
+const MAS: &'static [char] = &['M', 'A', 'S'];
+
+fn dfs(
+    grid: &[String],
+    stack: &mut VecDeque<char>,
+    r: i32,
+    c: i32,
+    max_r: i32,
+    max_c: i32,
+    (x, y): (i32, i32),
+) -> i32 {
+    if stack.len() == 3 {
+        return 1;
+    }
+    let r = r + x;
+    let c = c + y;
+    if r < 0 || c < 0 || r >= max_r || c >= max_c {
+        return 0;
+    }
+    let mut met = 0;
+    let which_xmas_char = stack.len();
+    let xmas_char = MAS[which_xmas_char];
+    let char = grid
+        .get(r as usize)
+        .unwrap()
+        .chars()
+        .nth(c as usize)
+        .unwrap();
+    if char != xmas_char {
+        return 0;
+    }
+    stack.push_back(char);
+    met += dfs(grid, stack, r, c, max_r, max_c, (x, y));
+    stack.pop_back();
+    met
+}
+
+const DIAGONALS: &'static [((i32, i32), (i32, i32))] = &[((-1, -1), (1, 1)), ((-1, 1), (1, -1))];
+const MAS_WORD: &'static str = "MAS";
+const SAM_WORD: &'static str = "SAM";
+
+fn is_x_mas(grid: &[String], r: i32, c: i32, max_r: i32, max_c: i32) -> bool {
+    let mut matches = 0;
+    //get string from cur position to
+    // (-1,-1), (r,c) (1,1)
+    // (-1,1), (r,c) (1,-1)
+    // if starts if S check if matches MAS or SAM
+    for ((x1, y1), (x2, y2)) in DIAGONALS {
+        if is_oob((r + x1, c + y1), (max_r, max_c)) || is_oob((r + x2, c + y2), (max_r, max_c)) {
+            return false;
+        }
+        let char_top = grid
+            .get((r + x1) as usize)
+            .unwrap()
+            .chars()
+            .nth((c + y1) as usize)
+            .unwrap();
+        let char_mid = grid
+            .get(r as usize)
+            .unwrap()
+            .chars()
+            .nth(c as usize)
+            .unwrap();
+        let char_bot = grid
+            .get((r + x2) as usize)
+            .unwrap()
+            .chars()
+            .nth((c + y2) as usize)
+            .unwrap();
+        let s = format!("{}{}{}", char_top, char_mid, char_bot);
+        if s == MAS_WORD || s == SAM_WORD {
+            matches += 1;
+        }
+    }
+    matches == 2
+}
+
+fn is_oob((r, c): (i32, i32), (max_r, max_c): (i32, i32)) -> bool {
+    r < 0 || c < 0 || r >= max_r || c >= max_c
+}
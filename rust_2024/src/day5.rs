@@ -1,15 +1,18 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs,
-};
+use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
+use aoc_utils::solution::Solution;
 use itertools::Itertools;
 
-fn main() -> Result<()> {
-    let files = ["./inputs/day5.test", "./inputs/day5.prod"];
-    for file in files {
-        let input = fs::read_to_string(file)?;
+pub struct Day5;
+
+impl Solution for Day5 {
+    /// Pages that have to come after each page, and the updates
+    type Input = (HashMap<i32, HashSet<i32>>, Vec<Vec<i32>>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
         let parts = input.split("\n\n").collect_vec();
         let mut number_afters: HashMap<i32, HashSet<i32>> = HashMap::new();
         parts.get(0).unwrap().lines().for_each(|line| {
@@ -20,7 +23,7 @@ fn main() -> Result<()> {
                 .unwrap();
             number_afters.entry(x).or_insert(HashSet::new()).insert(y);
         });
-        let incorrect_orders: Vec<Vec<i32>> = parts
+        let orders = parts
             .get(1)
             .unwrap()
             .lines()
@@ -29,16 +32,33 @@ fn main() -> Result<()> {
                     .map(|x| x.parse::<i32>().unwrap())
                     .collect_vec()
             })
+            .collect_vec();
+        Ok((number_afters, orders))
+    }
+
+    fn part1((number_afters, orders): &Self::Input) -> Result<i32> {
+        let sum: i32 = orders
+            .iter()
+            .filter(|ordering| filter_for_correct_orders(ordering, number_afters))
+            .map(|order| *order.get(order.len() / 2).unwrap())
+            .sum();
+        Ok(sum)
+    }
+
+    fn part2((number_afters, orders): &Self::Input) -> Result<i32> {
+        let mut number_afters = number_afters.clone();
+        let incorrect_orders: Vec<Vec<i32>> = orders
+            .iter()
             .filter(|ordering| !filter_for_correct_orders(ordering, &number_afters))
+            .cloned()
             .collect_vec();
         let mut sum = 0;
         for mut inorder in incorrect_orders {
             inorder.sort_by(|a, b| number_afters.entry(*b).or_default().contains(a).cmp(&true));
             sum += inorder[inorder.len() / 2];
         }
-        println!("{file}: {sum}");
+        Ok(sum)
     }
-    Ok(())
 }
 
 fn filter_for_correct_orders(
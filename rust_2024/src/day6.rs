@@ -1,44 +1,7 @@
-use std::{collections::HashSet, fs};
+use std::collections::HashSet;
 
 use anyhow::Result;
-
-#[derive(Copy, Debug, Clone, PartialEq, Hash, Eq)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-impl Direction {
-    fn resolve_dir(self) -> (i32, i32) {
-        match self {
-            Direction::Left => (-1, 0),
-            Direction::Down => (0, 1),
-            Direction::Right => (1, 0),
-            Direction::Up => (0, -1),
-        }
-    }
-
-    fn rot_right(&self) -> Direction {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
-    }
-}
-
-fn which_dir(c: char) -> Direction {
-    match c {
-        'v' => Direction::Down,
-        '<' => Direction::Left,
-        '^' => Direction::Up,
-        '>' => Direction::Right,
-        _ => panic!("Wrong char used! {c}!"),
-    }
-}
+use aoc_utils::{grid::Direction, solution::Solution};
 
 enum Tile {
     Wall,
@@ -52,23 +15,42 @@ struct CordDir {
     dir: Direction,
 }
 
-fn main() -> Result<()> {
-    let files = ["./inputs/day6.test", "./inputs/day6.prod"];
-    for file in files {
-        let input = fs::read_to_string(file)?;
-        let input: Vec<String> = input
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
             .split("\n")
             .filter(|line| !line.is_empty())
             .map(|str| String::from(str))
-            .collect();
-        let Some((x, y)) = find_start(&input) else {
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let Some((x, y)) = find_start(input) else {
+            panic!("Start not found");
+        };
+        let start_char = input[y as usize].chars().nth(x as usize).unwrap();
+        let dir = Direction::try_from(start_char)?;
+        let mut path = Vec::new();
+        walk(input, (x, y), dir, &mut path);
+        let visited: HashSet<(i32, i32)> = path.iter().map(|c| (c.x, c.y)).collect();
+        Ok(visited.len())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let Some((x, y)) = find_start(input) else {
             panic!("Start not found");
         };
         let start_char = input[y as usize].chars().nth(x as usize).unwrap();
-        let dir = which_dir(start_char);
+        let dir = Direction::try_from(start_char)?;
         //brute forcing this first, go over each tile and put the obstacle, check if cycle then
         let mut path = Vec::new();
-        walk(&input, (x, y), dir, &mut path);
+        walk(input, (x, y), dir, &mut path);
         let start = CordDir { x, y, dir };
         //when I was testing it I met some cords that were duplicated because of different
         //directions
@@ -77,7 +59,7 @@ fn main() -> Result<()> {
             if cord_dir.x == start.x && cord_dir.y == start.y {
                 continue;
             }
-            if has_cycle(&input, &start, (cord_dir.x, cord_dir.y)) {
+            if has_cycle(input, &start, (cord_dir.x, cord_dir.y)) {
                 obstacles.insert((cord_dir.x, cord_dir.y));
             }
         }
@@ -85,20 +67,23 @@ fn main() -> Result<()> {
         //2. for each tile do has cycle
         //detecting cycle: if the same obstacle was hit twice with the from the same direction
 
-        println!("{file}: {}", obstacles.len());
+        Ok(obstacles.len())
     }
-    Ok(())
 }
 
 fn has_cycle(input: &Vec<String>, start: &CordDir, obstacle: (i32, i32)) -> bool {
     let mut cur = start.clone();
     let mut already_hit_obstacles: HashSet<CordDir> = HashSet::new();
     loop {
-        if cur.x < 0 || cur.y < 0 || cur.y >= (input.len() as i32) || cur.x >= (input[0].len() as i32) {
+        if cur.x < 0
+            || cur.y < 0
+            || cur.y >= (input.len() as i32)
+            || cur.x >= (input[0].len() as i32)
+        {
             return false;
         }
         let mut c = input[cur.y as usize].chars().nth(cur.x as usize).unwrap();
-        let (x1, y1) = cur.dir.resolve_dir();
+        let (x1, y1) = step(cur.dir);
         if cur.x == obstacle.0 && cur.y == obstacle.1 {
             c = '#';
         }
@@ -110,7 +95,7 @@ fn has_cycle(input: &Vec<String>, start: &CordDir, obstacle: (i32, i32)) -> bool
                 already_hit_obstacles.insert(cur);
                 cur.x -= x1;
                 cur.y -= y1;
-                cur.dir = cur.dir.rot_right();
+                cur.dir = cur.dir.rotate_clockwise();
             }
             Tile::Dot => {
                 cur.x += x1;
@@ -131,12 +116,12 @@ fn walk(
             break;
         }
         let c = input[y as usize].chars().nth(x as usize).unwrap();
-        let (x1, y1) = dir.resolve_dir();
+        let (x1, y1) = step(dir);
         match match_tile(c) {
             Tile::Wall => {
                 x -= x1;
                 y -= y1;
-                dir = dir.rot_right();
+                dir = dir.rotate_clockwise();
             }
             Tile::Dot => {
                 path.push(CordDir { x, y, dir });
@@ -147,6 +132,12 @@ fn walk(
     }
 }
 
+/// The guard walks in (x, y), x being the column
+fn step(dir: Direction) -> (i32, i32) {
+    let v = dir.resolve();
+    (v.c as i32, v.r as i32)
+}
+
 fn match_tile(c: char) -> Tile {
     match c {
         '.' | 'v' | '<' | '^' | '>' => Tile::Dot,
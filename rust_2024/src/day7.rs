@@ -1,9 +1,8 @@
-use std::fs;
-
 use anyhow::Result;
+use aoc_utils::solution::Solution;
 use itertools::Itertools;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 enum Operator {
     Add,
     Multiply,
@@ -13,6 +12,7 @@ enum Operator {
 fn possibility(
     result: usize,
     numbers: &Vec<usize>,
+    operators: &[Operator],
     operation: Operator,
     mut cur: usize,
     idx: usize,
@@ -31,9 +31,9 @@ fn possibility(
                     cur = tmp.parse().unwrap();
                 }
             }
-            return possibility(result, numbers, Operator::Add, cur, idx + 1)
-                || possibility(result, numbers, Operator::Multiply, cur, idx + 1)
-                || possibility(result, numbers, Operator::Concat, cur, idx + 1);
+            return operators
+                .iter()
+                .any(|&op| possibility(result, numbers, operators, op, cur, idx + 1));
         }
         None => {
             //Used all numbers -- basecase
@@ -45,22 +45,40 @@ fn possibility(
     }
 }
 
-fn main() -> Result<()> {
-    let files = ["./inputs/day7.test", "./inputs/day7.prod"];
-    for file in files {
-        let result: usize = fs::read_to_string(file)?
-            .lines()
-            .map(|line| parse_equation(line))
-            .filter(|(result, numbers)| {
-                possibility(*result, numbers, Operator::Add, 0, 0)
-                    || possibility(*result, numbers, Operator::Multiply, 0, 0)
-                    || possibility(*result, numbers, Operator::Concat, 0, 0)
-            })
-            .map(|(result, _)| result)
-            .sum();
-        println!("{file}: {}", result);
+/// Sum of the results that can be made with `operators`
+fn calibration(equations: &[(usize, Vec<usize>)], operators: &[Operator]) -> usize {
+    equations
+        .iter()
+        .filter(|(result, numbers)| {
+            operators
+                .iter()
+                .any(|&op| possibility(*result, numbers, operators, op, 0, 0))
+        })
+        .map(|(result, _)| result)
+        .sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(usize, Vec<usize>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(|line| parse_equation(line)).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(calibration(input, &[Operator::Add, Operator::Multiply]))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(calibration(
+            input,
+            &[Operator::Add, Operator::Multiply, Operator::Concat],
+        ))
     }
-    Ok(())
 }
 
 fn parse_equation(input: &str) -> (usize, Vec<usize>) {
@@ -1,19 +1,54 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs,
-};
+use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
+use aoc_utils::solution::Solution;
 use itertools::Itertools;
 
-fn main() -> Result<()> {
-    let files = ["./inputs/day8.test", "./inputs/day8.prod"];
-    for file in files {
-        let mut antinodes: HashSet<(i32, i32)> = HashSet::new();
-        let input: Vec<String> = fs::read_to_string(file)?
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
             .lines()
             .map(|line| String::from(line.trim()))
-            .collect_vec();
+            .collect_vec())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let mut antinodes: HashSet<(i32, i32)> = HashSet::new();
+        let max_y = input.len() as i32;
+        let max_x = input[0].len() as i32;
+        //group all antenas(x,y) by frequncy
+        //then for each combination of antesnas
+        // measure the distance between and
+        // add do antena + distance
+        // if it's in the board => insert into hashset as they must be unique
+        let grouped_antenas = group_antenas(input);
+        for cords in grouped_antenas.values() {
+            for &cord in cords.iter() {
+                for &cord2 in cords.iter() {
+                    if cord == cord2 {
+                        continue;
+                    }
+                    let (dx, dy) = distance(cord, cord2);
+                    let (mut x, mut y) = cord;
+                    x = x - dx;
+                    y = y - dy;
+                    if x >= 0 && y >= 0 && x < max_x && y < max_y {
+                        antinodes.insert((x, y));
+                    }
+                }
+            }
+        }
+        Ok(antinodes.len())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let mut antinodes: HashSet<(i32, i32)> = HashSet::new();
         let max_y = input.len() as i32;
         let max_x = input[0].len() as i32;
         //group all antenas(x,y) by frequncy
@@ -21,7 +56,7 @@ fn main() -> Result<()> {
         // measure the distance between and
         // add do antena + distance
         // if it's in the board => insert into hashset as they must be unique
-        let grouped_antenas = group_antenas(&input);
+        let grouped_antenas = group_antenas(input);
         for cords in grouped_antenas.values() {
             for &cord in cords.iter() {
                 for &cord2 in cords.iter() {
@@ -38,10 +73,8 @@ fn main() -> Result<()> {
                 }
             }
         }
-        println!("{}: {}", file, antinodes.len());
+        Ok(antinodes.len())
     }
-
-    Ok(())
 }
 
 #[derive(Debug, Hash, Eq, PartialEq)]
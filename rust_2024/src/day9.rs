@@ -0,0 +1,202 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use aoc_utils::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MemoryBlock {
+    id: usize,
+    value: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Block(MemoryBlock),
+    Skip,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct StoredFile {
+    length: usize,
+    fs_idx: usize,
+}
+
+impl StoredFile {
+    fn checksum(&self, id: usize) -> usize {
+        (self.fs_idx..self.fs_idx + self.length)
+            .map(|i| i * id)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SkipLength {
+    length: usize,
+    fs_idx: usize,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Block {
+    File(StoredFile),
+    Skip(SkipLength),
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    /// The disk map, part 1 moves single blocks and part 2 whole files
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(input: &String) -> Result<usize> {
+        let input = input.clone();
+        let mut file_system = parse_input_into_segments(input);
+        let mut result = 0;
+        let mut last_idx = file_system.len() - 1;
+        while file_system[last_idx] == Segment::Skip {
+            last_idx -= 1;
+        }
+        for i in 0..file_system.len() {
+            if i > last_idx {
+                break;
+            }
+            let s = &file_system[i];
+            match s {
+                Segment::Block(memory_block) => {
+                    result += i * memory_block.id;
+                }
+                Segment::Skip => {
+                    //move from last indx to skip
+                    match &file_system[last_idx] {
+                        Segment::Block(memory_block) => {
+                            result += i * memory_block.id;
+                            file_system[i] = Segment::Block(*memory_block);
+                            file_system[last_idx] = Segment::Skip;
+                            while file_system[last_idx] == Segment::Skip {
+                                last_idx -= 1;
+                            }
+                        }
+                        Segment::Skip => (),
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    // thanks u/jinschoi for his submission on Reddit. Helped me a lot to udnerstand
+    fn part2(input: &String) -> Result<usize> {
+        let input = input.clone();
+        let file_system = parse_input_into_blocks(input);
+        // print_fs(&file_system);
+        let mut files: Vec<StoredFile> = Vec::new();
+        //spaces indexed by size and orderd by index of appearance
+        let mut spaces: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); 10];
+        for f in file_system {
+            match f {
+                Block::File(stored_file) => {
+                    files.push(stored_file);
+                }
+                Block::Skip(skip_length) => {
+                    if skip_length.length > 0 {
+                        spaces[skip_length.length].insert(skip_length.fs_idx);
+                    }
+                }
+            }
+        }
+        for id in (0..files.len()).rev() {
+            // find file with id
+            let file = &mut files[id];
+            // first first empty slot larger than length and below idx
+            let empty = (file.length..10)
+                .flat_map(|length| {
+                    let free_idxs = &spaces[length];
+                    free_idxs
+                        .range(0..file.fs_idx)
+                        .map(move |&free_idx| (length, free_idx))
+                })
+                .min_by_key(|(_, idx)| *idx);
+            if let Some((length, empty_idx)) = empty {
+                // remove empty idx and add a new, smaller one if necessary.
+                spaces[length].remove(&empty_idx);
+                if length > file.length {
+                    spaces[length - file.length].insert(empty_idx + file.length);
+                }
+                // adjust file's idx
+                file.fs_idx = empty_idx;
+                continue;
+            }
+        }
+        let result: usize = files.iter().enumerate().map(|(i, f)| f.checksum(i)).sum();
+
+        Ok(result)
+    }
+}
+
+#[allow(dead_code)]
+fn print_fs(fs: &Vec<Segment>) {
+    for s in fs {
+        match s {
+            Segment::Block(memory_block) => print!("{}", memory_block.id),
+            Segment::Skip => print!("."),
+        }
+    }
+    println!();
+}
+
+fn parse_input_into_segments(input: String) -> Vec<Segment> {
+    // println!("{input}");
+    let mut aoc_fs = Vec::with_capacity(input.len());
+    let mut block_id = 0;
+    for i in 0..input.len() {
+        let c = input.chars().nth(i).unwrap();
+        let c = c.to_digit(10).expect(&format!("Couldn't parse {}", c));
+        if i % 2 == 0 {
+            // println!("char: {c} => memory");
+            //memory
+            for _ in 0..c {
+                aoc_fs.push(Segment::Block(MemoryBlock {
+                    id: block_id,
+                    value: c as usize,
+                }));
+            }
+            block_id += 1;
+        } else {
+            //skips
+            // println!("char: {c} => skip");
+            for _ in 0..c {
+                aoc_fs.push(Segment::Skip);
+            }
+        }
+    }
+    aoc_fs
+}
+
+fn parse_input_into_blocks(input: String) -> Vec<Block> {
+    let mut aoc_fs: Vec<Block> = Vec::new();
+    let mut idx = 0;
+    for i in 0..input.len() {
+        let c = input.chars().nth(i).unwrap();
+        let c = c.to_digit(10).expect(&format!("Couldn't parse {}", c));
+        if i % 2 == 0 {
+            //memory
+            aoc_fs.push(Block::File(StoredFile {
+                fs_idx: idx,
+                length: c as usize,
+            }));
+        } else {
+            //skips
+            aoc_fs.push(Block::Skip(SkipLength {
+                fs_idx: idx,
+                length: c as usize,
+            }));
+        }
+        idx += c as usize;
+    }
+    aoc_fs
+}
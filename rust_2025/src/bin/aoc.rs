@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    aoc_utils::cli::main(2025, rust_2025::SOLUTIONS)
+}
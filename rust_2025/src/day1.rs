@@ -0,0 +1,145 @@
+use anyhow::{Result, anyhow};
+use aoc_utils::solution::Solution;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(anyhow!("Didn't expect this character to be here - {s}")),
+        };
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Rotation {
+    direction: Direction,
+    dist: usize,
+}
+impl FromStr for Rotation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (di, ds) = s.split_at(1);
+        let direction = Direction::from_str(di)?;
+        let dist = ds.parse::<usize>()?;
+        Ok(Rotation { direction, dist })
+    }
+}
+const MAX: usize = 100;
+fn rotate(mut cur: usize, rot: &mut Rotation) -> usize {
+    if rot.dist >= MAX {
+        rot.dist = rot.dist % MAX;
+    }
+    match rot.direction {
+        Direction::Left => match cur.checked_sub(rot.dist) {
+            Some(new) => cur = new,
+            None => cur = MAX - (rot.dist - cur),
+        },
+        Direction::Right => {
+            cur += rot.dist;
+            if cur >= MAX {
+                cur -= MAX;
+            }
+        }
+    }
+    cur
+}
+
+/// Like `rotate` but also counts how many times the dial passes 0
+fn rotate_with_ticks(mut cur: usize, rot: &mut Rotation) -> (usize, usize) {
+    let mut ticks = 0;
+    if rot.dist >= MAX {
+        ticks = rot.dist / MAX;
+        rot.dist = rot.dist % MAX;
+    }
+    match rot.direction {
+        Direction::Left => match cur.checked_sub(rot.dist) {
+            Some(new) => cur = new,
+            None => {
+                if cur != 0 {
+                    ticks += 1;
+                }
+                cur = MAX - (rot.dist - cur);
+            }
+        },
+        Direction::Right => {
+            cur += rot.dist;
+            if cur >= MAX {
+                cur -= MAX;
+                if cur != 0 {
+                    ticks += 1;
+                }
+            }
+        }
+    }
+    (cur, ticks)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Rotation>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|line| Rotation::from_str(line).expect(&format!("Couldn't parse line {}", line)))
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let mut cur: usize = 50;
+        let mut res: usize = 0;
+        for rot in input {
+            cur = rotate(cur, &mut rot.clone());
+            if cur == 0 {
+                res += 1;
+            }
+        }
+        Ok(res)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let mut cur: usize = 50;
+        let mut res: usize = 0;
+        for rot in input {
+            let (newcur, ticks) = rotate_with_ticks(cur, &mut rot.clone());
+            cur = newcur;
+            res += ticks;
+            if cur == 0 {
+                res += 1;
+            }
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part_1() {
+        let file = "./inputs/day1.test";
+        let result = Day1::solve_part1(file).unwrap();
+        let expected = 3;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn part_2() {
+        let file = "./inputs/day1.test";
+        let result = Day1::solve_part2(file).unwrap();
+        let expected = 6;
+        assert_eq!(result, expected)
+    }
+}
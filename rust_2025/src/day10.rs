@@ -0,0 +1,106 @@
+use anyhow::Result;
+use aoc_utils::{
+    nom::{braced, bracketed, list, parenthesized, parse_all, uint},
+    reader, search,
+    solution::{Solution, Unsolved},
+};
+use itertools::Itertools;
+use nom::{
+    character::complete::{one_of, space1},
+    combinator::map,
+    multi::many1,
+    sequence::{preceded, tuple},
+};
+use std::str::FromStr;
+
+type Input<T> = Vec<T>;
+
+#[derive(Debug)]
+pub struct Machine {
+    indicators: Vec<bool>,
+    buttons: Vec<Vec<usize>>,
+    joltage: Vec<usize>,
+}
+
+fn press_buttons(current_indicators: &[bool], buttons: &Vec<usize>) -> Vec<bool> {
+    let mut new_state = current_indicators.to_vec();
+    for b in buttons {
+        new_state[*b] = !current_indicators[*b];
+    }
+    new_state
+}
+
+fn bfs(machine: &Machine) -> usize {
+    let start = vec![false; machine.indicators.len()];
+    let path = search::bfs(
+        start,
+        |state| {
+            machine
+                .buttons
+                .iter()
+                .map(|b| press_buttons(state, b))
+                .collect_vec()
+        },
+        |state| *state == machine.indicators,
+    );
+    path.expect("Couldn't reach the indicator state").cost
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Input<Machine>;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(reader::parse_lines_str(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let mut res = 0;
+        for machine in input {
+            res += bfs(machine)
+        }
+
+        Ok(res)
+    }
+
+    // part 2 isn't solved yet
+}
+
+impl FromStr for Machine {
+    type Err = anyhow::Error;
+
+    // [.##.] (3) (1,3) (2) {3,5,4,7}
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let indicators = bracketed(many1(map(one_of(".#"), |c| c == '#')));
+        let buttons = list(" ", parenthesized(list(",", uint)));
+        let joltage = braced(list(",", uint));
+        let (indicators, buttons, joltage) = parse_all(
+            s,
+            tuple((
+                indicators,
+                preceded(space1, buttons),
+                preceded(space1, joltage),
+            )),
+        )?;
+        Ok(Self {
+            indicators,
+            buttons,
+            joltage,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part_1() {
+        let file = "./inputs/day10.test";
+        let result = Day10::solve_part1(file).unwrap();
+        let expected = 7;
+        assert_eq!(result, expected)
+    }
+}
@@ -1,7 +1,8 @@
 use anyhow::Result;
+use aoc_utils::solution::Solution;
 use rayon::prelude::*;
 use std::collections::HashMap;
-use std::{collections::HashSet, time::Instant};
+use std::collections::HashSet;
 
 type Input = HashMap<String, HashSet<String>>;
 
@@ -24,13 +25,6 @@ fn walk(cur: &String, input: &Input) -> usize {
     return sum;
 }
 
-fn solve(file_name: &str) -> Result<usize> {
-    let input: Input = read_input(file_name)?;
-    let res = walk(&String::from("you"), &input);
-
-    Ok(res)
-}
-
 fn walk_2(
     cur: &String,
     input: &Input,
@@ -61,52 +55,46 @@ fn walk_2(
     cache.insert(key, sum);
     sum
 }
-fn solve_2(file_name: &str) -> Result<usize> {
-    let input: Input = read_input(file_name)?;
-    let starting_branches: Vec<&String> = input
-        .get("svr")
-        .expect("Input doesn't have 'svr' in it!")
-        .iter()
-        .collect();
-
-    let all_paths: usize = starting_branches
-        .par_iter()
-        .map(|branch| {
-            // cache: (node, has_dac, has_fft) -> path count
-            let mut cache: HashMap<(String, bool, bool), usize> = HashMap::new();
-            walk_2(branch, &input, false, false, &mut cache)
-        })
-        .sum();
-    Ok(all_paths)
-}
 
-fn read_input(file_name: &str) -> Result<Input> {
-    let mut input = HashMap::new();
-    aoc_utils::reader::read_lines(file_name)?
-        .map_while(Result::ok)
-        .for_each(|line| {
-            let (device_name, outs) = parse_input_line(&line);
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Input> {
+        let mut input = HashMap::new();
+        text.lines().for_each(|line| {
+            let (device_name, outs) = parse_input_line(line);
             input.insert(device_name, outs);
         });
-    Ok(input)
-}
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Result<usize> {
+        let res = walk(&String::from("you"), input);
 
-fn main() -> Result<()> {
-    let files = ["./inputs/day11.test", "./inputs/day11.prod"];
-    println!("# Part 1");
-    for file in files {
-        let now = Instant::now();
-        let res = solve(file)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
+        Ok(res)
     }
-    let files = ["./inputs/day11.test2", "./inputs/day11.prod"];
-    println!("# Part 2");
-    for file in files {
-        let now = Instant::now();
-        let res = solve_2(file)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
+
+    fn part2(input: &Input) -> Result<usize> {
+        let starting_branches: Vec<&String> = input
+            .get("svr")
+            .expect("Input doesn't have 'svr' in it!")
+            .iter()
+            .collect();
+
+        let all_paths: usize = starting_branches
+            .par_iter()
+            .map(|branch| {
+                // cache: (node, has_dac, has_fft) -> path count
+                let mut cache: HashMap<(String, bool, bool), usize> = HashMap::new();
+                walk_2(branch, input, false, false, &mut cache)
+            })
+            .sum();
+        Ok(all_paths)
     }
-    Ok(())
 }
 
 #[cfg(test)]
@@ -115,7 +103,7 @@ mod tests {
     #[test]
     fn part_1() {
         let file = "./inputs/day11.test";
-        let result = solve(file).unwrap();
+        let result = Day11::solve_part1(file).unwrap();
         let expected = 5;
         assert_eq!(result, expected)
     }
@@ -123,7 +111,7 @@ mod tests {
     #[test]
     fn part_2() {
         let file = "./inputs/day11.test2";
-        let result = solve_2(file).unwrap();
+        let result = Day11::solve_part2(file).unwrap();
         let expected = 2;
         assert_eq!(result, expected)
     }
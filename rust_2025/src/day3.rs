@@ -1,5 +1,5 @@
 use anyhow::Result;
-use std::{fs, time::Instant};
+use aoc_utils::solution::Solution;
 
 type Input<T> = Vec<Vec<T>>;
 
@@ -20,39 +20,37 @@ fn max_joltage(bank: &Vec<usize>, num_of_digits: usize) -> usize {
     d * (10_usize.pow(num_of_digits as u32 - 1)) + max_joltage(&right.to_vec(), num_of_digits - 1)
 }
 
-fn solve(file_name: &str, digits: usize) -> Result<usize> {
-    let input: Input<usize> = read_input(file_name)?;
+fn solve(input: &Input<usize>, digits: usize) -> Result<usize> {
     let res = input.iter().map(|bank| max_joltage(bank, digits)).sum();
     Ok(res)
 }
 
-fn read_input(file_name: &str) -> Result<Input<usize>> {
-    Ok(fs::read_to_string(file_name)?
-        .lines()
-        .map(|line| {
-            line.trim()
-                .chars()
-                .map(|c| c.to_digit(10).expect("Unexpected character {c}!") as usize)
-                .collect()
-        })
-        .collect())
-}
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Input<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-fn main() -> Result<()> {
-    let files = ["./inputs/day3.test", "./inputs/day3.prod"];
-    println!("Part 1");
-    for file in files {
-        let now = Instant::now();
-        let res = solve(file, 2)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                line.trim()
+                    .chars()
+                    .map(|c| c.to_digit(10).expect("Unexpected character {c}!") as usize)
+                    .collect()
+            })
+            .collect())
     }
-    println!("Part 2");
-    for file in files {
-        let now = Instant::now();
-        let res = solve(file, 12)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        solve(input, 2)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        solve(input, 12)
     }
-    Ok(())
 }
 
 #[cfg(test)]
@@ -61,14 +59,14 @@ mod tests {
     #[test]
     fn test_part1() {
         let file = "./inputs/day3.test";
-        let result = solve(file,2).unwrap();
+        let result = Day3::solve_part1(file).unwrap();
         let expected = 357;
         assert_eq!(result, expected)
     }
     #[test]
     fn test_part2() {
         let file = "./inputs/day3.test";
-        let result = solve(file,12).unwrap();
+        let result = Day3::solve_part2(file).unwrap();
         let expected = 3121910778619;
         assert_eq!(result, expected)
     }
@@ -0,0 +1,71 @@
+use anyhow::Result;
+use aoc_utils::{grid::ByteGrid, solution::Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = ByteGrid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<ByteGrid> {
+        Ok(ByteGrid::from_bytes(input.as_bytes().to_vec())?)
+    }
+
+    fn part1(input: &ByteGrid) -> Result<usize> {
+        let mut res = 0;
+        for (p, roll) in input.iter() {
+            if roll == b'@' {
+                let count = input.neighbors8(p).filter(|(_, _, n)| *n == b'@').count();
+                if count < 4 {
+                    res += 1;
+                }
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn part2(input: &ByteGrid) -> Result<usize> {
+        let mut res = 0;
+        let mut input = input.clone();
+        loop {
+            let mut removed = 0;
+
+            for (p, roll) in input.clone().iter() {
+                if roll == b'@' {
+                    let count = input.neighbors8(p).filter(|(_, _, n)| *n == b'@').count();
+                    if count < 4 {
+                        input[p] = b'.';
+                        removed += 1;
+                    }
+                }
+            }
+            res += removed;
+            if removed == 0 {
+                break;
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part1() {
+        let file = "./inputs/day4.test";
+        let result = Day4::solve_part1(file).unwrap();
+        let expected = 13;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn test_part2() {
+        let file = "./inputs/day4.test";
+        let result = Day4::solve_part2(file).unwrap();
+        let expected = 43;
+        assert_eq!(result, expected)
+    }
+}
@@ -1,6 +1,7 @@
 use anyhow::Result;
+use aoc_utils::{reader::split_sections, solution::Solution};
 use itertools::Itertools;
-use std::{cmp, fs, ops::RangeInclusive, time::Instant};
+use std::{cmp, ops::RangeInclusive};
 
 type Input<T> = Vec<T>;
 
@@ -24,41 +25,6 @@ fn merge_intervals(intervals: &mut Vec<RangeInclusive<usize>>) -> Vec<RangeInclu
     merged_intervals
 }
 
-fn solve_1(file_name: &str) -> Result<usize> {
-    let input: Input<String> = read_input(file_name)?;
-    let mut intervals = parse_intervals(&input[0]);
-    let intervals = merge_intervals(&mut intervals);
-    let res = input[1]
-        .lines()
-        .map(|id| id.parse::<usize>().expect("Couldn't parse {id}"))
-        .filter(|id| {
-            for itrv in &intervals {
-                if itrv.contains(id) {
-                    return true;
-                }
-            }
-            false
-        })
-        .count();
-
-    Ok(res)
-}
-fn solve_2(file_name: &str) -> Result<usize> {
-    let input: Input<String> = read_input(file_name)?;
-    let mut intervals = parse_intervals(&input[0]);
-    let merged = merge_intervals(&mut intervals);
-    let res = merged
-        .into_iter()
-        .map(|itrv| {
-            let start = itrv.start();
-            let end = itrv.end();
-            end - start + 1
-        })
-        .sum();
-
-    Ok(res)
-}
-
 fn parse_intervals(input: &String) -> Vec<RangeInclusive<usize>> {
     let res = input
         .lines()
@@ -77,28 +43,51 @@ fn parse_intervals(input: &String) -> Vec<RangeInclusive<usize>> {
     res
 }
 
-fn read_input(file_name: &str) -> Result<Input<String>> {
-    Ok(fs::read_to_string(file_name)?
-        .split("\n\n")
-        .map(|s| s.to_string())
-        .collect_vec())
-}
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Input<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    /// The fresh ranges and the available ids
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(split_sections(input))
+    }
 
-fn main() -> Result<()> {
-    let files = ["./inputs/day5.test", "./inputs/day5.prod"];
-    println!("# Part 1");
-    for file in files {
-        let now = Instant::now();
-        let res = solve_1(file)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let mut intervals = parse_intervals(&input[0]);
+        let intervals = merge_intervals(&mut intervals);
+        let res = input[1]
+            .lines()
+            .map(|id| id.parse::<usize>().expect("Couldn't parse {id}"))
+            .filter(|id| {
+                for itrv in &intervals {
+                    if itrv.contains(id) {
+                        return true;
+                    }
+                }
+                false
+            })
+            .count();
+
+        Ok(res)
     }
-    println!("# Part 2");
-    for file in files {
-        let now = Instant::now();
-        let res = solve_2(file)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let mut intervals = parse_intervals(&input[0]);
+        let merged = merge_intervals(&mut intervals);
+        let res = merged
+            .into_iter()
+            .map(|itrv| {
+                let start = itrv.start();
+                let end = itrv.end();
+                end - start + 1
+            })
+            .sum();
+
+        Ok(res)
     }
-    Ok(())
 }
 
 #[cfg(test)]
@@ -107,14 +96,14 @@ mod tests {
     #[test]
     fn test_part1() {
         let file = "./inputs/day5.test";
-        let result = solve_1(file).unwrap();
+        let result = Day5::solve_part1(file).unwrap();
         let expected = 3;
         assert_eq!(result, expected)
     }
     #[test]
     fn test_part2() {
         let file = "./inputs/day5.test";
-        let result = solve_2(file).unwrap();
+        let result = Day5::solve_part2(file).unwrap();
         let expected = 14;
         assert_eq!(result, expected)
     }
@@ -0,0 +1,88 @@
+use anyhow::Result;
+use aoc_utils::{
+    parse::{Block, column_blocks},
+    solution::Solution,
+};
+
+fn calculate(sign: &str, numbers: &[String]) -> usize {
+    let mut ans = 0;
+    if sign == "*" {
+        ans = 1;
+    }
+    for n in numbers {
+        add_number(sign, &mut ans, n);
+    }
+    ans
+}
+
+fn add_number(sign: &str, cul: &mut usize, n: &String) {
+    let n = n
+        .trim()
+        .parse::<usize>()
+        .expect(format!("Couldn't parse '{}'", n).as_str());
+
+    match sign {
+        "+" => *cul += n,
+        "*" => *cul *= n,
+        _ => panic!("Unkown sign '{}'", sign),
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Block>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    /// The worksheet split into problems on the blank columns
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(column_blocks(input))
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let mut res = 0;
+        for block in input {
+            let mut rows: Vec<String> = block.rows().collect();
+            let sign = rows.pop().expect("Block without a sign");
+            res += calculate(sign.trim(), &rows);
+        }
+        Ok(res)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let mut res = 0;
+        for block in input {
+            let sign = block.rows().last().expect("Block without a sign");
+            // every column read top to bottom is a number once the sign row is dropped
+            let numbers: Vec<String> = block
+                .columns()
+                .map(|mut column| {
+                    column.pop();
+                    column
+                })
+                .collect();
+            res += calculate(sign.trim(), &numbers);
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_1() {
+        let file = "./inputs/day6.test";
+        let result = Day6::solve_part1(file).unwrap();
+        let expected = 4277556;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn test_part_2() {
+        let file = "./inputs/day6.test";
+        let result = Day6::solve_part2(file).unwrap();
+        let expected = 3263827;
+        assert_eq!(result, expected)
+    }
+}
@@ -0,0 +1,107 @@
+use anyhow::Result;
+use aoc_utils::{
+    grid::{Grid, Point, parse_grid},
+    solution::Solution,
+};
+use std::collections::{HashMap, HashSet};
+
+fn merge_timelines(
+    new_beams: &mut HashMap<Point, usize>,
+    new_beam_point: Point,
+    old_beam_point: Point,
+    prev_timelines: usize,
+) {
+    if new_beams.contains_key(&new_beam_point) {
+        *new_beams.get_mut(&new_beam_point).expect("err") += prev_timelines;
+    } else {
+        new_beams.remove(&old_beam_point);
+        new_beams.insert(new_beam_point, prev_timelines);
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = (Grid<char>, Point);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    /// Manifold with the position of the beam source
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (grid, [start]) = parse_grid(input, [('S', '.')], |c| c)?;
+        Ok((grid, start))
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let mut splits = 0;
+        let (grid, (sr, sc)) = (&input.0, input.1);
+        let mut beams: HashSet<Point> = HashSet::new();
+        beams.insert((sr + 1, sc));
+        for r in sr + 2..grid.height() {
+            let mut new_beams: HashSet<Point> = HashSet::new();
+            for (_, bc) in beams.into_iter() {
+                if grid[(r, bc)] == '^' {
+                    splits += 1;
+                    if bc != 0 {
+                        new_beams.insert((r, bc - 1));
+                    }
+                    if bc + 1 < grid.width() {
+                        new_beams.insert((r, bc + 1));
+                    }
+                } else {
+                    new_beams.insert((r, bc));
+                }
+            }
+            beams = new_beams;
+        }
+
+        Ok(splits)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let mut timelines = 0;
+        let (grid, (sr, sc)) = (&input.0, input.1);
+        let mut beams: HashMap<Point, usize> = HashMap::new();
+        beams.insert((sr + 1, sc), 1);
+        for r in sr + 2..grid.height() {
+            let mut new_beams: HashMap<Point, usize> = HashMap::new();
+            for ((br, bc), prev_timelines) in beams.into_iter() {
+                if grid[(r, bc)] == '^' {
+                    if bc != 0 {
+                        merge_timelines(&mut new_beams, (r, bc - 1), (br, bc), prev_timelines);
+                    }
+                    if bc + 1 < grid.width() {
+                        merge_timelines(&mut new_beams, (r, bc + 1), (br, bc), prev_timelines);
+                    }
+                } else {
+                    merge_timelines(&mut new_beams, (r, bc), (br, bc), prev_timelines);
+                }
+            }
+            beams = new_beams;
+        }
+        for (_, t) in beams {
+            timelines += t;
+        }
+
+        Ok(timelines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_1() {
+        let file = "./inputs/day7.test";
+        let result = Day7::solve_part1(file).unwrap();
+        let expected = 21;
+        assert_eq!(result, expected)
+    }
+    #[test]
+    fn test_part_2() {
+        let file = "./inputs/day7.test";
+        let result = Day7::solve_part2(file).unwrap();
+        let expected = 40;
+        assert_eq!(result, expected)
+    }
+}
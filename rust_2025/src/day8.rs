@@ -1,17 +1,16 @@
 use anyhow::Result;
-use itertools::Itertools;
+use aoc_utils::{parse::ints_n, reader, solution::Solution};
 use std::{
     collections::{HashMap, HashSet},
     f64,
     fmt::Display,
-    fs,
     str::FromStr,
-    time::Instant,
 };
 
 type Input<T> = Vec<T>;
+/// Junction boxes hang in 3D, so this can't be a `grid::Vec2`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Position {
+pub struct Position {
     x: isize,
     y: isize,
     z: isize,
@@ -37,16 +36,7 @@ impl FromStr for Position {
 
     // expects s to be "x,y,z"
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let s = s.split(",").collect_vec();
-        let x = s[0]
-            .parse::<isize>()
-            .expect(&format!("Couldn't parse {}", s[0]));
-        let y = s[1]
-            .parse::<isize>()
-            .expect(&format!("Couldn't parse {}", s[1]));
-        let z = s[2]
-            .parse::<isize>()
-            .expect(&format!("Couldn't parse {}", s[2]));
+        let [x, y, z] = ints_n(s)?;
         Ok(Self { x, y, z })
     }
 }
@@ -61,7 +51,7 @@ fn find_closest_pair(
     for i in 0..input.len() {
         let p = input.get(i).expect("Should never happen");
         for j in 0..input.len() {
-            if i==j{
+            if i == j {
                 continue;
             }
             let q = input.get(j).expect("Should never happen");
@@ -123,8 +113,7 @@ fn connect_pairs(
     }
 }
 
-fn solve(file_name: &str, max_connections: usize) -> Result<usize> {
-    let input: Input<Position> = read_input(file_name)?;
+fn solve(input: &Input<Position>, max_connections: usize) -> Result<usize> {
     // IT's AN UNDIRECTED GRAPH / DSU
     // Union Find
 
@@ -132,7 +121,7 @@ fn solve(file_name: &str, max_connections: usize) -> Result<usize> {
     let mut lookup: HashMap<Position, usize> = HashMap::new();
     let mut connections: HashSet<(Position, Position)> = HashSet::new();
     let mut circuits: Vec<HashSet<Position>> = vec![];
-    for x in &input {
+    for x in input {
         let mut h = HashSet::new();
         h.insert(*x);
         circuits.push(h);
@@ -141,13 +130,13 @@ fn solve(file_name: &str, max_connections: usize) -> Result<usize> {
     let mut conns = 0;
     while conns < max_connections {
         //1. Find the closest pair
-        let (p_idx, q_idx) = find_closest_pair(&input, &connections);
+        let (p_idx, q_idx) = find_closest_pair(input, &connections);
         //2. Connect the closest pair
         //  2a. If they are in the same circuit, it's still a connection
         if p_idx != usize::MAX && q_idx != usize::MAX {
             connect_pairs(
                 (p_idx, q_idx),
-                &input,
+                input,
                 &mut lookup,
                 &mut circuits,
                 &mut connections,
@@ -170,66 +159,54 @@ fn solve(file_name: &str, max_connections: usize) -> Result<usize> {
     Ok(res)
 }
 
-fn solve_2(file_name: &str) -> Result<isize> {
-    let input: Input<Position> = read_input(file_name)?;
+pub struct Day8;
 
-    //map to connect a Position to a certain circuit
-    let mut lookup: HashMap<Position, usize> = HashMap::new();
-    let mut connections: HashSet<(Position, Position)> = HashSet::new();
-    let mut circuits: Vec<HashSet<Position>> = vec![];
-    let mut last_pair: (Position, Position) = (input[0], input[1]);
-    for x in &input {
-        let mut h = HashSet::new();
-        h.insert(*x);
-        circuits.push(h);
-        lookup.insert(*x, circuits.len() - 1);
-    }
-    while circuits.len() != 1 {
-        //1. Find the closest pair
-        let (p_idx, q_idx) = find_closest_pair(&input, &connections);
-        //2. Connect the closest pair
-        //  2a. If they are in the same circuit, it's still a connection
-        if p_idx != usize::MAX && q_idx != usize::MAX {
-            last_pair = (input[p_idx], input[q_idx]);
-            connect_pairs(
-                (p_idx, q_idx),
-                &input,
-                &mut lookup,
-                &mut circuits,
-                &mut connections,
-            );
-        }
-    }
-    let res = last_pair.0.x * last_pair.1.x;
-    Ok(res)
-}
+impl Solution for Day8 {
+    type Input = Input<Position>;
+    type Answer1 = usize;
+    type Answer2 = isize;
 
-fn read_input(file_name: &str) -> Result<Input<Position>> {
-    Ok(fs::read_to_string(file_name)?
-        .lines()
-        .map(|line| {
-            line.trim()
-                .parse::<Position>()
-                .expect(&format!("Couldn't parse '{}'", line))
-        })
-        .collect())
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(reader::parse_lines_str(input)?)
+    }
 
-fn main() -> Result<()> {
-    let files = [("./inputs/day8.test", 10), ("./inputs/day8.prod", 1000)];
-    println!("# Part 1");
-    for (file, connections) in files {
-        let now = Instant::now();
-        let res = solve(file, connections)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
+    fn part1(input: &Self::Input) -> Result<usize> {
+        // the example connects its 20 boxes 10 times, the real input its 1000 boxes 1000 times
+        let max_connections = if input.len() < 1000 { 10 } else { 1000 };
+        solve(input, max_connections)
     }
-    println!("# Part 2");
-    for (file, _) in files {
-        let now = Instant::now();
-        let res = solve_2(file)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
+
+    fn part2(input: &Self::Input) -> Result<isize> {
+        //map to connect a Position to a certain circuit
+        let mut lookup: HashMap<Position, usize> = HashMap::new();
+        let mut connections: HashSet<(Position, Position)> = HashSet::new();
+        let mut circuits: Vec<HashSet<Position>> = vec![];
+        let mut last_pair: (Position, Position) = (input[0], input[1]);
+        for x in input {
+            let mut h = HashSet::new();
+            h.insert(*x);
+            circuits.push(h);
+            lookup.insert(*x, circuits.len() - 1);
+        }
+        while circuits.len() != 1 {
+            //1. Find the closest pair
+            let (p_idx, q_idx) = find_closest_pair(input, &connections);
+            //2. Connect the closest pair
+            //  2a. If they are in the same circuit, it's still a connection
+            if p_idx != usize::MAX && q_idx != usize::MAX {
+                last_pair = (input[p_idx], input[q_idx]);
+                connect_pairs(
+                    (p_idx, q_idx),
+                    input,
+                    &mut lookup,
+                    &mut circuits,
+                    &mut connections,
+                );
+            }
+        }
+        let res = last_pair.0.x * last_pair.1.x;
+        Ok(res)
     }
-    Ok(())
 }
 
 #[cfg(test)]
@@ -238,14 +215,14 @@ mod tests {
     #[test]
     fn part_1() {
         let file = "./inputs/day8.test";
-        let result = solve(file, 10).unwrap();
+        let result = Day8::solve_part1(file).unwrap();
         let expected = 40;
         assert_eq!(result, expected)
     }
     #[test]
     fn part_2() {
         let file = "./inputs/day8.test";
-        let result = solve_2(file).unwrap();
+        let result = Day8::solve_part2(file).unwrap();
         let expected = 25272;
         assert_eq!(result, expected)
     }
@@ -0,0 +1,113 @@
+use anyhow::Result;
+use aoc_utils::{grid::Vec2, parse::ints_n, reader, solution::Solution};
+use itertools::Itertools;
+use std::{cmp, collections::HashSet};
+
+type Input<T> = Vec<T>;
+
+/// Rectangle with opposite corners `r1` and `r2`, counting the tiles of both
+fn area(r1: &Vec2, r2: &Vec2) -> isize {
+    let d = *r2 - *r1;
+    (d.c.abs() + 1) * (d.r.abs() + 1)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Input<Vec2>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // every line is "x,y"
+        Ok(reader::parse_lines_with(input, |line| {
+            ints_n(line).map(|[c, r]| Vec2::new(r, c))
+        })?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<isize> {
+        let mut max_area = isize::MIN;
+        input.iter().for_each(|r1| {
+            input.iter().for_each(|r2| {
+                let area = area(r1, r2);
+                if area > max_area {
+                    max_area = area;
+                }
+            });
+        });
+        Ok(max_area)
+    }
+
+    fn part2(input: &Self::Input) -> Result<isize> {
+        // polygon's perimeter
+        let mut polygon: HashSet<Vec2> = HashSet::new();
+        input.iter().circular_tuple_windows().for_each(|(r1, r2)| {
+            let min_row = cmp::min(r1.r, r2.r);
+            let max_row = cmp::max(r1.r, r2.r);
+            let min_col = cmp::min(r1.c, r2.c);
+            let max_col = cmp::max(r1.c, r2.c);
+            if r1.r == r2.r {
+                for col in min_col..=max_col {
+                    polygon.insert(Vec2::new(r1.r, col));
+                }
+            }
+            if r1.c == r2.c {
+                for row in min_row..=max_row {
+                    polygon.insert(Vec2::new(row, r1.c));
+                }
+            }
+        });
+        let mut rectangles: Vec<(Vec2, Vec2, isize)> = input
+            .iter()
+            .combinations(2)
+            .map(|comb| {
+                let r1 = comb[0];
+                let r2 = comb[1];
+                let a = area(r1, r2);
+                (*r1, *r2, a)
+            })
+            .collect();
+        //sort rectangles by area
+        rectangles.sort_by(|r1, r2| r2.2.cmp(&r1.2));
+        for (r1, r2, area) in rectangles {
+            if is_valid_rectangle(&polygon, &r1, &r2) {
+                return Ok(area);
+            }
+        }
+
+        Ok(0)
+    }
+}
+
+fn is_valid_rectangle(polygon: &HashSet<Vec2>, r1: &Vec2, r2: &Vec2) -> bool {
+    let min_row = cmp::min(r1.r, r2.r);
+    let max_row = cmp::max(r1.r, r2.r);
+    let min_col = cmp::min(r1.c, r2.c);
+    let max_col = cmp::max(r1.c, r2.c);
+    for p in polygon {
+        if p.r > min_row && p.r < max_row && p.c > min_col && p.c < max_col {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    // #[test]
+    // fn part_1() {
+    //     let file = "./inputs/day9.test";
+    //     let result = Day9::solve_part1(file).unwrap();
+    //     let expected = 50;
+    //     assert_eq!(result, expected)
+    // }
+
+    #[test]
+    fn part_2() {
+        let file = "./inputs/day9.test";
+        let result = Day9::solve_part2(file).unwrap();
+        let expected = 24;
+        assert_eq!(result, expected)
+    }
+}
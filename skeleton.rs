@@ -1,46 +1,35 @@
 use anyhow::Result;
-use std::{fs, time::Instant};
+use aoc_utils::solution::Solution;
 
 type Input<T> = Vec<Vec<T>>;
 
-fn solve(file_name: &str) -> Result<usize> {
-    let mut res = 0;
-    let input: Input<char> = read_input(file_name)?;
-    println!("{:?}", input);
+pub struct Day12;
 
-    Ok(res)
-}
+impl Solution for Day12 {
+    type Input = Input<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-fn solve_2(file_name: &str) -> Result<usize> {
-    let mut res = 0;
-    let input: Input<char> = read_input(file_name)?;
-    println!("{:?}", input);
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect())
+    }
 
-    Ok(res)
-}
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let res = 0;
+        println!("{:?}", input);
 
-fn read_input(file_name: &str) -> Result<Input<char>> {
-    Ok(fs::read_to_string(file_name)?
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect())
-}
-
-fn main() -> Result<()> {
-    let files = ["./inputs/day12.test", "./inputs/day12.prod"];
-    println!("# Part 1");
-    for file in files {
-        let now = Instant::now();
-        let res = solve(file)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
+        Ok(res)
     }
-    println!("# Part 2");
-    for file in files {
-        let now = Instant::now();
-        let res = solve_2(file)?;
-        println!("{}: {} in {}ms", file, res, now.elapsed().as_millis());
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let res = 0;
+        println!("{:?}", input);
+
+        Ok(res)
     }
-    Ok(())
 }
 
 #[cfg(test)]
@@ -49,7 +38,7 @@ mod tests {
     #[test]
     fn part_1() {
         let file = "./inputs/day12.test";
-        let result = solve(file).unwrap();
+        let result = Day12::solve_part1(file).unwrap();
         let expected = 140;
         assert_eq!(result, expected)
     }
@@ -57,7 +46,7 @@ mod tests {
     #[test]
     fn part_2() {
         let file = "./inputs/day12.test";
-        let result = solve_2(file).unwrap();
+        let result = Day12::solve_part2(file).unwrap();
         let expected = 140;
         assert_eq!(result, expected)
     }